@@ -0,0 +1,269 @@
+use crate::weighted::WeightedRadixTrie;
+use std::cmp::Ordering;
+
+/// AdaptiveCompleter suggests the completions users select the most and the latest.
+///
+/// Every selection adds one to the score of the label. With a decay below one,
+/// all the scores are multiplied by the decay on every selection,
+/// so the recent selections weigh more than the old ones.
+/// As every score decays at the same rate, a label is only reweighted when it is selected,
+/// and the suggestions are found best first from the cached highest weights of the subtrees
+///
+/// # Example
+/// ```rust
+/// use another_radix_trie::AdaptiveCompleter;
+///
+/// let mut completer = AdaptiveCompleter::<()>::new();
+/// completer.insert("git commit", ());
+/// completer.insert("git checkout", ());
+/// completer.insert("git clone", ());
+/// completer.record_selection("git clone");
+/// let res = completer.suggest("git c", 2);
+/// let expected = vec![(String::from("git clone"), &()), (String::from("git checkout"), &())];
+/// assert_eq!(res, expected);
+/// ```
+pub struct AdaptiveCompleter<T> {
+    /// The labels weighted by the logarithm of their scores as of the first selection,
+    /// which orders them the same as their current scores
+    trie: WeightedRadixTrie<Usage<T>, Score>,
+    decay: f64,
+    /// The number of selections recorded so far
+    clock: u64,
+}
+
+struct Usage<T> {
+    value: T,
+    /// The score as of the last selection of the label
+    score: f64,
+    last_selected: u64,
+}
+
+impl<T> Default for AdaptiveCompleter<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> AdaptiveCompleter<T> {
+    /// Construct a new completer ranking by the number of selections
+    pub fn new() -> Self {
+        Self::with_decay(1.0)
+    }
+
+    /// Construct a new completer whose scores are multiplied by the decay on every selection.
+    /// The decay must be within (0, 1]
+    /// # Example
+    /// ```rust
+    /// use another_radix_trie::AdaptiveCompleter;
+    ///
+    /// let mut completer = AdaptiveCompleter::<()>::with_decay(0.5);
+    /// completer.insert("cargo build", ());
+    /// completer.insert("cargo test", ());
+    /// completer.record_selection("cargo build");
+    /// completer.record_selection("cargo build");
+    /// completer.record_selection("cargo test");
+    /// // 0.5 * (0.5 + 1) for build, 1 for test
+    /// assert_eq!(completer.suggest("cargo", 1), vec![(String::from("cargo test"), &())]);
+    /// ```
+    pub fn with_decay(decay: f64) -> Self {
+        assert!(
+            decay > 0.0 && decay <= 1.0,
+            "Decay must be within (0, 1], got {}",
+            decay
+        );
+        AdaptiveCompleter {
+            trie: WeightedRadixTrie::new(),
+            decay,
+            clock: 0,
+        }
+    }
+
+    /// Insert label and associated value into the completer.
+    /// The value will be override if the label is already in the completer, but not the score
+    pub fn insert(&mut self, label: &str, value: T) {
+        if let Some(usage) = self.trie.find_mut(label) {
+            usage.value = value;
+            return;
+        }
+        let usage = Usage {
+            value,
+            score: 0.0,
+            last_selected: self.clock,
+        };
+        let weight = Self::weight(self.decay, &usage);
+        self.trie.insert(label, usage, weight);
+    }
+
+    /// Returns the borrowed value associated with related label
+    pub fn find(&self, label: &str) -> Option<&T> {
+        self.trie.find(label).map(|usage| &usage.value)
+    }
+
+    /// Removes the value associated with related label, along with its score.
+    /// If the provided label does not exist in the completer, return None
+    pub fn remove(&mut self, label: &str) -> Option<T> {
+        self.trie.remove(label).map(|usage| usage.value)
+    }
+
+    /// Records that the label is selected by the user, and adds one to its score.
+    /// Returns false if the label does not exist in the completer
+    pub fn record_selection(&mut self, label: &str) -> bool {
+        let clock = self.clock + 1;
+        let decay = self.decay;
+        let usage = match self.trie.find_mut(label) {
+            Some(usage) => usage,
+            None => return false,
+        };
+        usage.score = Self::decayed(decay, usage, clock) + 1.0;
+        usage.last_selected = clock;
+        let weight = Self::weight(decay, usage);
+        self.trie.set_weight(label, weight);
+        self.clock = clock;
+        true
+    }
+
+    /// Returns the current score of the label
+    pub fn score(&self, label: &str) -> Option<f64> {
+        let usage = self.trie.find(label)?;
+        Some(Self::decayed(self.decay, usage, self.clock))
+    }
+
+    /// Returns the k values with the highest scores, along with their labels,
+    /// where the labels start with the given prefix.
+    /// The values with the same score are in sorted order of the labels
+    pub fn suggest(&self, prefix: &str, k: usize) -> Vec<(String, &T)> {
+        self.trie
+            .top_completions(prefix, k)
+            .into_iter()
+            .map(|(label, usage, _)| (label, &usage.value))
+            .collect()
+    }
+
+    /// Returns the score of the usage at the given time
+    fn decayed(decay: f64, usage: &Usage<T>, clock: u64) -> f64 {
+        if decay == 1.0 {
+            return usage.score;
+        }
+        let elapsed = (clock - usage.last_selected) as f64;
+        usage.score * decay.powf(elapsed)
+    }
+
+    /// Returns the logarithm of the score of the usage at the time of the first selection.
+    /// The current score is this weight plus the logarithm of the decay times the clock,
+    /// which is the same for every label, so the weight needs no update until the next selection
+    fn weight(decay: f64, usage: &Usage<T>) -> Score {
+        Score(usage.score.ln() - usage.last_selected as f64 * decay.ln())
+    }
+}
+
+/// A score ordered by `f64::total_cmp`
+#[derive(Clone, Copy)]
+struct Score(f64);
+
+impl PartialEq for Score {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Score {}
+
+impl PartialOrd for Score {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Score {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.total_cmp(&other.0)
+    }
+}
+
+#[cfg(test)]
+mod adaptive_tests {
+    use crate::adaptive::AdaptiveCompleter;
+
+    fn labels(res: Vec<(String, &usize)>) -> Vec<String> {
+        res.into_iter().map(|(label, _)| label).collect()
+    }
+
+    fn get_test_completer(decay: f64) -> AdaptiveCompleter<usize> {
+        let mut completer = AdaptiveCompleter::with_decay(decay);
+        let commands = ["open file", "open folder", "open recent", "close file"];
+        for command in &commands {
+            completer.insert(command, command.len())
+        }
+        completer
+    }
+
+    #[test]
+    fn test_suggest_by_frequency() {
+        let mut completer = get_test_completer(1.0);
+        assert_eq!(
+            labels(completer.suggest("open", 5)),
+            vec!["open file", "open folder", "open recent"]
+        );
+        assert!(completer.record_selection("open recent"));
+        assert!(completer.record_selection("open folder"));
+        assert!(completer.record_selection("open recent"));
+        assert!(!completer.record_selection("open"));
+        assert_eq!(
+            labels(completer.suggest("open", 2)),
+            vec!["open recent", "open folder"]
+        );
+        assert_eq!(completer.score("open recent"), Some(2.0));
+        // the score stays when the value is override
+        completer.insert("open recent", 0);
+        assert_eq!(completer.score("open recent"), Some(2.0));
+        assert_eq!(completer.find("open recent"), Some(&0));
+        assert_eq!(completer.remove("open recent"), Some(0));
+        assert_eq!(labels(completer.suggest("open", 1)), vec!["open folder"]);
+    }
+
+    #[test]
+    fn test_suggest_with_decay() {
+        let mut completer = get_test_completer(0.5);
+        for _ in 0..3 {
+            completer.record_selection("open file");
+        }
+        completer.record_selection("open recent");
+        // 1.75 * 0.5 for "open file", 1 for "open recent"
+        assert_eq!(completer.score("open file"), Some(0.875));
+        assert_eq!(
+            labels(completer.suggest("open", 3)),
+            vec!["open recent", "open file", "open folder"]
+        );
+        completer.record_selection("open file");
+        assert_eq!(labels(completer.suggest("", 1)), vec!["open file"]);
+    }
+
+    #[test]
+    fn test_suggest_follows_scores() {
+        let mut completer = get_test_completer(0.8);
+        let selections = [
+            "open file",
+            "close file",
+            "open recent",
+            "open file",
+            "open folder",
+            "close file",
+            "open folder",
+        ];
+        for label in &selections {
+            completer.record_selection(label);
+            let mut expected = ["open file", "open folder", "open recent", "close file"]
+                .iter()
+                .map(|label| (completer.score(label).unwrap(), label.to_string()))
+                .collect::<Vec<_>>();
+            expected.sort_by(|(score1, label1), (score2, label2)| {
+                score2.total_cmp(score1).then(label1.cmp(label2))
+            });
+            let expected = expected
+                .into_iter()
+                .map(|(_, label)| label)
+                .collect::<Vec<_>>();
+            assert_eq!(labels(completer.suggest("", 4)), expected, "{}", label);
+        }
+    }
+}
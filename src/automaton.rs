@@ -0,0 +1,159 @@
+use crate::element::Element;
+use crate::normalizer::KeyNormalizer;
+use crate::ordering::KeyOrdering;
+use crate::trie::RadixTrie;
+
+/// A finite automaton run over the chars of the labels of a trie by `RadixTrie::search`.
+/// The subtrees are skipped once `can_match` returns false
+///
+/// # Example
+/// ```rust
+/// use another_radix_trie::{Automaton, RadixTrie};
+///
+/// /// Matches the labels without the given char
+/// struct Without(char);
+///
+/// impl Automaton for Without {
+///     type State = bool;
+///
+///     fn start(&self) -> bool {
+///         true
+///     }
+///
+///     fn accept(&self, state: &bool, c: char) -> bool {
+///         *state && c != self.0
+///     }
+///
+///     fn is_match(&self, state: &bool) -> bool {
+///         *state
+///     }
+///
+///     fn can_match(&self, state: &bool) -> bool {
+///         *state
+///     }
+/// }
+///
+/// let mut trie = RadixTrie::<usize>::new();
+/// trie.insert("Won", 1);
+/// trie.insert("Wonder", 2);
+/// trie.insert("World", 3);
+/// assert_eq!(trie.search(&Without('e')), vec![(String::from("Won"), &1), (String::from("World"), &3)]);
+/// ```
+pub trait Automaton {
+    /// The state of the automaton after some chars are accepted
+    type State: Clone;
+
+    /// Returns the state before any char is accepted
+    fn start(&self) -> Self::State;
+
+    /// Returns the state after the char is accepted
+    fn accept(&self, state: &Self::State, c: char) -> Self::State;
+
+    /// Returns true if the chars accepted so far are matched
+    fn is_match(&self, state: &Self::State) -> bool;
+
+    /// Returns false if no more chars can lead to a match.
+    /// The default implementation never gives up
+    fn can_match(&self, _state: &Self::State) -> bool {
+        true
+    }
+}
+
+impl<T, O, N> RadixTrie<T, O, N, str>
+where
+    O: KeyOrdering,
+    N: KeyNormalizer,
+{
+    /// Returns all values with their labels where the labels are matched by the automaton,
+    /// in sorted order of the labels
+    pub fn search<A: Automaton>(&self, automaton: &A) -> Vec<(String, &T)> {
+        let mut label = String::new();
+        let mut results = vec![];
+        let state = automaton.start();
+        if automaton.can_match(&state) {
+            for child in self.root().children() {
+                Self::search_visit(child, automaton, &state, &mut label, &mut results);
+            }
+        }
+        results
+    }
+
+    fn search_visit<'a, A: Automaton>(
+        element: &'a Element<T>,
+        automaton: &A,
+        state: &A::State,
+        label: &mut String,
+        results: &mut Vec<(String, &'a T)>,
+    ) {
+        let label_len = label.len();
+        let mut state = state.clone();
+        for c in element.label().chars() {
+            state = automaton.accept(&state, c);
+            if !automaton.can_match(&state) {
+                label.truncate(label_len);
+                return;
+            }
+            label.push(c);
+        }
+        if let Some(value) = element.value() {
+            if automaton.is_match(&state) {
+                results.push((element.key(label).to_owned(), value));
+            }
+        }
+        for child in element.children() {
+            Self::search_visit(child, automaton, &state, label, results);
+        }
+        label.truncate(label_len);
+    }
+}
+
+#[cfg(test)]
+mod automaton_tests {
+    use crate::automaton::Automaton;
+    use crate::trie::RadixTrie;
+    use std::cell::Cell;
+
+    /// Matches the labels with the exact number of chars, counting the accepted chars
+    struct Length {
+        len: usize,
+        accepted: Cell<usize>,
+    }
+
+    impl Automaton for Length {
+        type State = usize;
+
+        fn start(&self) -> usize {
+            0
+        }
+
+        fn accept(&self, state: &usize, _c: char) -> usize {
+            self.accepted.set(self.accepted.get() + 1);
+            state + 1
+        }
+
+        fn is_match(&self, state: &usize) -> bool {
+            *state == self.len
+        }
+
+        fn can_match(&self, state: &usize) -> bool {
+            *state <= self.len
+        }
+    }
+
+    #[test]
+    fn test_search_prunes() {
+        let mut trie = RadixTrie::<usize>::new();
+        let words = ["Won", "Wonder", "Wonderful", "Axe", "Axes"];
+        for word in &words {
+            trie.insert(word, word.len())
+        }
+        let automaton = Length {
+            len: 3,
+            accepted: Cell::new(0),
+        };
+        let res = trie.search(&automaton);
+        assert_eq!(res, vec![("Axe".into(), &3), ("Won".into(), &3)]);
+        // "Axe", "s", "Won" and the first char of "der"
+        assert_eq!(automaton.accepted.get(), 8);
+    }
+}
@@ -0,0 +1,203 @@
+use crate::key::TrieKey;
+use crate::normalizer::{Identity, KeyNormalizer};
+use crate::ordering::{KeyOrdering, Lexicographic};
+use crate::rank::TopK;
+use crate::trie::RadixTrie;
+
+/// RadixCounter counts the occurrences of strings.
+/// A label is removed from the counter once its count drops to zero
+///
+/// # Example
+/// ```rust
+/// use another_radix_trie::RadixCounter;
+///
+/// let mut counter = RadixCounter::new();
+/// counter.increment("the", 3);
+/// counter.increment("then", 1);
+/// assert_eq!(counter.get("the"), 3);
+/// assert_eq!(counter.decrement("the", 5), 0);
+/// assert_eq!(counter.get("the"), 0);
+/// assert_eq!(counter.total_under("th"), 1);
+/// ```
+pub struct RadixCounter<O = Lexicographic, N = Identity, K: ?Sized + TrieKey = str> {
+    trie: RadixTrie<u64, O, N, K>,
+}
+
+impl<O, N, K> Default for RadixCounter<O, N, K>
+where
+    K: ?Sized + TrieKey,
+    O: KeyOrdering<K::Unit> + Default,
+    N: KeyNormalizer<K> + Default,
+{
+    fn default() -> Self {
+        Self::with_ordering_and_normalizer(O::default(), N::default())
+    }
+}
+
+impl RadixCounter {
+    /// Construct a new counter.
+    /// Counters with other key types are constructed with `RadixCounter::default`
+    pub fn new() -> Self {
+        Self::with_ordering_and_normalizer(Lexicographic, Identity)
+    }
+}
+
+impl<O, K: ?Sized + TrieKey> RadixCounter<O, Identity, K> {
+    /// Construct a new counter whose labels are sorted with the given ordering
+    pub fn with_ordering(ordering: O) -> Self {
+        Self::with_ordering_and_normalizer(ordering, Identity)
+    }
+}
+
+impl<N, K: ?Sized + TrieKey> RadixCounter<Lexicographic, N, K> {
+    /// Construct a new counter whose labels are normalized with the given normalizer
+    pub fn with_normalizer(normalizer: N) -> Self {
+        Self::with_ordering_and_normalizer(Lexicographic, normalizer)
+    }
+}
+
+impl<O, N, K: ?Sized + TrieKey> RadixCounter<O, N, K> {
+    /// Construct a new counter whose labels are sorted with the given ordering,
+    /// and normalized with the given normalizer
+    pub fn with_ordering_and_normalizer(ordering: O, normalizer: N) -> Self {
+        RadixCounter {
+            trie: RadixTrie::with_ordering_and_normalizer(ordering, normalizer),
+        }
+    }
+}
+
+impl<O, N, K> RadixCounter<O, N, K>
+where
+    K: ?Sized + TrieKey,
+    O: KeyOrdering<K::Unit>,
+    N: KeyNormalizer<K>,
+{
+    /// Adds to the count of the label, inserting the label if it is not counted yet.
+    /// Returns the new count
+    pub fn increment(&mut self, label: &K, by: u64) -> u64 {
+        if by == 0 {
+            return self.get(label);
+        }
+        match self.trie.find_or_insert_with(label, || 0) {
+            Some((count, _)) => {
+                *count = count.saturating_add(by);
+                *count
+            }
+            None => 0,
+        }
+    }
+
+    /// Subtracts from the count of the label, removing the label once its count drops to zero.
+    /// Returns the new count
+    pub fn decrement(&mut self, label: &K, by: u64) -> u64 {
+        let count = match self.trie.find_mut(label) {
+            Some(count) => count,
+            None => return 0,
+        };
+        *count = count.saturating_sub(by);
+        let count = *count;
+        if count == 0 {
+            self.trie.remove(label);
+        }
+        count
+    }
+
+    /// Returns the count of the label, which is zero if the label is not counted
+    pub fn get(&self, label: &K) -> u64 {
+        self.trie.find(label).copied().unwrap_or(0)
+    }
+
+    /// Returns the sum of the counts of all labels starting with the given prefix
+    pub fn total_under(&self, prefix: &K) -> u64 {
+        self.trie
+            .prefix_iter(prefix)
+            .fold(0, |total, (_, count)| total.saturating_add(*count))
+    }
+
+    /// Returns the n labels with the highest counts, from the highest to the lowest.
+    /// Labels with the same count are in sorted order
+    /// # Example
+    /// ```rust
+    /// use another_radix_trie::RadixCounter;
+    ///
+    /// let mut counter = RadixCounter::new();
+    /// counter.increment("b", 2);
+    /// counter.increment("a", 2);
+    /// counter.increment("c", 5);
+    /// counter.increment("d", 1);
+    /// let expected = vec![(String::from("c"), 5), (String::from("a"), 2)];
+    /// assert_eq!(counter.most_common(2), expected);
+    /// ```
+    pub fn most_common(&self, n: usize) -> Vec<(K::Owned, u64)> {
+        let mut top = TopK::new(n);
+        for (label, count) in self.trie.iter() {
+            if top.accepts(count) {
+                top.push(*count, label);
+            }
+        }
+        top.into_sorted_vec()
+            .into_iter()
+            .map(|(count, label)| (label, count))
+            .collect()
+    }
+
+    /// Returns an iterator over all labels with their counts, in sorted order
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = (K::Owned, u64)> + '_ {
+        self.trie.iter().map(|(label, count)| (label, *count))
+    }
+}
+
+#[cfg(test)]
+mod counter_tests {
+    use crate::counter::RadixCounter;
+    use crate::ordering::CaseInsensitive;
+
+    #[test]
+    fn test_increment_decrement() {
+        let mut counter = RadixCounter::new();
+        assert_eq!(counter.increment("exe", 1), 1);
+        assert_eq!(counter.increment("execute", 2), 2);
+        assert_eq!(counter.increment("exec", 3), 3);
+        assert_eq!(counter.increment("exe", 4), 5);
+        assert_eq!(counter.increment("", 4), 0);
+        assert_eq!(counter.increment("ex", 0), 0);
+        assert_eq!(counter.decrement("exec", 1), 2);
+        assert_eq!(counter.decrement("exec", 2), 0);
+        assert_eq!(counter.decrement("ex", 2), 0);
+        let res = counter.iter().collect::<Vec<_>>();
+        assert_eq!(res, vec![("exe".into(), 5), ("execute".into(), 2)]);
+    }
+
+    #[test]
+    fn test_total_under() {
+        let mut counter = RadixCounter::with_ordering(CaseInsensitive);
+        counter.increment("Won", 1);
+        counter.increment("won", 2);
+        counter.increment("Wonder", 4);
+        counter.increment("World", 8);
+        assert_eq!(counter.get("WON"), 3);
+        assert_eq!(counter.total_under("won"), 7);
+        assert_eq!(counter.total_under("w"), 15);
+        assert_eq!(counter.total_under(""), 15);
+        assert_eq!(counter.total_under("x"), 0);
+    }
+
+    #[test]
+    fn test_most_common() {
+        let mut counter = RadixCounter::new();
+        for (word, count) in &[("d", 3), ("a", 1), ("c", 3), ("b", 7), ("e", 1)] {
+            counter.increment(word, *count);
+        }
+        let res = counter.most_common(4);
+        let expected = vec![
+            ("b".into(), 7),
+            ("c".into(), 3),
+            ("d".into(), 3),
+            ("a".into(), 1),
+        ];
+        assert_eq!(res, expected);
+        assert_eq!(counter.most_common(10).len(), 5);
+        assert_eq!(counter.most_common(usize::MAX).len(), 5);
+        assert_eq!(counter.most_common(0), vec![]);
+    }
+}
@@ -1,19 +1,24 @@
+use crate::key::TrieKey;
+use std::borrow::Borrow;
 use std::collections::VecDeque;
+use std::fmt;
 
-#[derive(Debug)]
-pub enum Element<T> {
+pub enum Element<T, K: ?Sized + TrieKey = str> {
     Value {
-        label: String,
+        label: K::Owned,
+        /// The key as it was inserted, only kept if it is spelled differently from the joined labels,
+        /// which happens when the ordering treats different units as equal
+        spelling: Option<K::Owned>,
         value: T,
-        children: Vec<Element<T>>,
+        children: Vec<Element<T, K>>,
     },
     Node {
-        label: String,
-        children: Vec<Element<T>>,
+        label: K::Owned,
+        children: Vec<Element<T, K>>,
     },
     Base {
-        label: String,
-        children: Vec<Element<T>>,
+        label: K::Owned,
+        children: Vec<Element<T, K>>,
     },
 }
 
@@ -24,6 +29,7 @@ macro_rules! unpack {
                 label,
                 value,
                 children,
+                ..
             } => (label, Some(value), children),
             Element::Node { label, children } => (label, None, children),
             Element::Base { label, children } => (label, None, children),
@@ -31,43 +37,44 @@ macro_rules! unpack {
     };
 }
 
-impl<T> Element<T> {
+impl<T, K: ?Sized + TrieKey> Element<T, K> {
     pub fn remove_label_prefix(&mut self, prefix_len: usize) {
-        self.label_mut().replace_range(..prefix_len, "");
+        K::remove_owned_prefix(self.label_mut(), prefix_len);
     }
 
-    pub fn add_label_prefix<S: AsRef<str>>(&mut self, prefix: S) {
-        self.label_mut().insert_str(0, prefix.as_ref());
+    pub fn add_label_prefix(&mut self, prefix: &K) {
+        K::insert_owned_prefix(self.label_mut(), prefix);
     }
 
-    pub fn label(&self) -> &str {
-        unpack!(self).0
+    pub fn label(&self) -> &K {
+        unpack!(self).0.borrow()
     }
 
-    fn label_mut(&mut self) -> &mut String {
+    fn label_mut(&mut self) -> &mut K::Owned {
         unpack!(self).0
     }
 
-    pub fn children_mut(&mut self) -> &mut Vec<Element<T>> {
+    pub fn children_mut(&mut self) -> &mut Vec<Element<T, K>> {
         unpack!(self).2
     }
 
-    pub fn children(&self) -> &Vec<Element<T>> {
+    pub fn children(&self) -> &Vec<Element<T, K>> {
         unpack!(self).2
     }
 
     /// Converts the Element::Node to Element::Value
-    pub fn node_to_value(node: &mut Element<T>, value: T) {
-        assert!(matches!(node, Element::Node {..}));
+    pub fn node_to_value(node: &mut Element<T, K>, spelling: Option<K::Owned>, value: T) {
+        assert!(matches!(node, Element::Node { .. }));
         let children = node.take_children();
         *node = Element::Value {
-            label: node.label().to_string(),
+            label: node.label().to_owned(),
+            spelling,
             value,
             children,
         }
     }
 
-    fn take_children(&mut self) -> Vec<Element<T>> {
+    fn take_children(&mut self) -> Vec<Element<T, K>> {
         let children = self.children_mut();
         let mut old = Vec::with_capacity(children.capacity());
         while let Some(child) = children.pop() {
@@ -77,6 +84,18 @@ impl<T> Element<T> {
         old
     }
 
+    /// Returns the key of the element as it was inserted,
+    /// given the joined labels of the element and its ancestors
+    pub fn key<'a>(&'a self, joined: &'a K) -> &'a K {
+        match self {
+            Element::Value {
+                spelling: Some(spelling),
+                ..
+            } => spelling.borrow(),
+            _ => joined,
+        }
+    }
+
     pub fn value(&self) -> Option<&T> {
         unpack!(self).1
     }
@@ -86,49 +105,74 @@ impl<T> Element<T> {
     }
 
     pub fn is_node(&self) -> bool {
-        match self {
-            Element::Node {
-                label: _,
-                children: _,
-            } => true,
-            _ => false,
-        }
+        matches!(self, Element::Node { .. })
     }
 
     /// Unpack element into label, value, and children
-    pub fn unpack(self) -> (String, Option<T>, Vec<Element<T>>) {
+    pub fn unpack(self) -> (K::Owned, Option<T>, Vec<Element<T, K>>) {
         unpack!(self)
     }
 
-    /// Collect all the descendant values with their labels
-    pub fn collect_all_child_values(&self) -> Vec<(String, &T)> {
+    /// Collect all the descendant values with their keys,
+    /// given the joined labels of the ancestors of the element
+    pub fn collect_all_child_values(&self, prefix: &K) -> Vec<(K::Owned, &T)> {
         // contains all the parent labels
-        let mut labels = vec![self.label().to_owned()];
+        let mut label = prefix.to_owned();
+        K::push_owned(&mut label, self.label());
         let mut res = match self.value() {
-            Some(value) => vec![(self.label().to_owned(), value)],
+            Some(value) => vec![(self.key(label.borrow()).to_owned(), value)],
             None => vec![],
         };
+        let mut labels = vec![label];
         // for all children with value, pack the parent label with the child element
         let mut children = self
             .children()
-            .into_iter()
+            .iter()
             .map(|child| (labels.len() - 1, child))
             .collect::<VecDeque<_>>();
         while let Some((prefix_index, element)) = children.pop_front() {
             // if element is Value, get the value and joined label
-            let label = format!("{}{}", labels[prefix_index], element.label());
+            let mut label = labels[prefix_index].borrow().to_owned();
+            K::push_owned(&mut label, element.label());
             labels.push(label);
             let index = labels.len() - 1;
             if let Some(value) = element.value() {
-                res.push((labels[index].to_owned(), value));
+                res.push((element.key(labels[index].borrow()).to_owned(), value));
             }
             // update the label storage
-            children.extend(element.children().into_iter().map(|child| (index, child)))
+            children.extend(element.children().iter().map(|child| (index, child)))
         }
         res
     }
 }
 
+impl<T: fmt::Debug, K: ?Sized + TrieKey> fmt::Debug for Element<T, K>
+where
+    K::Owned: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Element::Value { .. } => "Value",
+            Element::Node { .. } => "Node",
+            Element::Base { .. } => "Base",
+        };
+        let (label, value, children) = unpack!(self);
+        let mut debug = f.debug_struct(name);
+        debug.field("label", label);
+        if let Element::Value {
+            spelling: Some(spelling),
+            ..
+        } = self
+        {
+            debug.field("spelling", spelling);
+        }
+        if let Some(value) = value {
+            debug.field("value", value);
+        }
+        debug.field("children", children).finish()
+    }
+}
+
 #[cfg(test)]
 mod element_tests {
     use crate::element::Element;
@@ -142,6 +186,7 @@ mod element_tests {
                 children: vec![
                     Element::Value {
                         label: "ustry".into(),
+                        spelling: None,
                         value: (),
                         children: vec![],
                     },
@@ -149,9 +194,11 @@ mod element_tests {
                         label: "ustri".into(),
                         children: vec![Element::Value {
                             label: "al".into(),
+                            spelling: None,
                             value: (),
                             children: vec![Element::Value {
                                 label: "ization".into(),
+                                spelling: None,
                                 value: (),
                                 children: vec![],
                             }],
@@ -159,9 +206,11 @@ mod element_tests {
                     },
                     Element::Value {
                         label: "ia".into(),
+                        spelling: None,
                         value: (),
                         children: vec![Element::Value {
                             label: "n".into(),
+                            spelling: None,
                             value: (),
                             children: vec![],
                         }],
@@ -175,7 +224,7 @@ mod element_tests {
     fn test_collect_all_child_values() {
         let test_example = get_test_example();
         let res = test_example
-            .collect_all_child_values()
+            .collect_all_child_values("")
             .into_iter()
             .map(|(label, _)| label)
             .collect::<Vec<_>>();
@@ -0,0 +1,261 @@
+use crate::element::Element;
+use crate::iter::Iter;
+use crate::normalizer::KeyNormalizer;
+use crate::ordering::KeyOrdering;
+use crate::trie::RadixTrie;
+use std::cmp::Ordering;
+
+impl<T, O, N> RadixTrie<T, O, N, str>
+where
+    O: KeyOrdering,
+    N: KeyNormalizer,
+{
+    /// Returns all values with their labels and the edit distances of the labels to the query,
+    /// where the edit distances are within the given maximum, in sorted order of the labels.
+    /// The edit distance is the Levenshtein distance counted in chars,
+    /// and the chars are equal if the ordering of the trie says so
+    /// # Example
+    /// ```rust
+    /// use another_radix_trie::RadixTrie;
+    ///
+    /// let mut trie = RadixTrie::<usize>::new();
+    /// trie.insert("wonder", 1);
+    /// trie.insert("wander", 2);
+    /// trie.insert("wonderful", 3);
+    /// let expected = vec![
+    ///     (String::from("wander"), &2, 2),
+    ///     (String::from("wonder"), &1, 1),
+    /// ];
+    /// assert_eq!(trie.fuzzy_find("wondr", 2), expected);
+    /// ```
+    pub fn fuzzy_find(&self, query: &str, max_distance: usize) -> Vec<(String, &T, usize)> {
+        let query = self.normalize(query);
+        let mut levenshtein = Levenshtein::new(&query, max_distance, self.ordering());
+        let mut results = vec![];
+        for child in self.root().children() {
+            Self::fuzzy_visit(child, &mut levenshtein, &mut results);
+        }
+        results
+    }
+
+    /// Returns all values with their labels where some prefixes of the labels are within
+    /// the given edit distance to the query, in sorted order of the labels.
+    /// The smallest edit distance among the prefixes of each label is returned along with it
+    /// # Example
+    /// ```rust
+    /// use another_radix_trie::RadixTrie;
+    ///
+    /// let mut trie = RadixTrie::<usize>::new();
+    /// trie.insert("Wonderful", 1);
+    /// trie.insert("World", 2);
+    /// let expected = vec![(String::from("Wonderful"), &1, 2)];
+    /// assert_eq!(trie.fuzzy_start_with("Wonedr", 2), expected);
+    /// ```
+    pub fn fuzzy_start_with(&self, prefix: &str, max_distance: usize) -> Vec<(String, &T, usize)> {
+        let prefix = self.normalize(prefix);
+        let mut levenshtein = Levenshtein::new(&prefix, max_distance, self.ordering());
+        let mut results = vec![];
+        let distance = levenshtein.distance();
+        for child in self.root().children() {
+            Self::fuzzy_prefix_visit(child, &mut levenshtein, distance, &mut results);
+        }
+        results
+    }
+
+    fn fuzzy_visit<'a>(
+        element: &'a Element<T>,
+        levenshtein: &mut Levenshtein<'_, O>,
+        results: &mut Vec<(String, &'a T, usize)>,
+    ) {
+        let depth = levenshtein.depth();
+        let mut reachable = true;
+        for c in element.label().chars() {
+            if !levenshtein.push(c) {
+                reachable = false;
+                break;
+            }
+        }
+        if reachable {
+            if let Some(value) = element.value() {
+                let distance = levenshtein.distance();
+                if distance <= levenshtein.max_distance {
+                    let key = element.key(&levenshtein.label).to_owned();
+                    results.push((key, value, distance));
+                }
+            }
+            for child in element.children() {
+                Self::fuzzy_visit(child, levenshtein, results);
+            }
+        }
+        levenshtein.truncate(depth);
+    }
+
+    /// The best distance is the smallest edit distance among the prefixes of the label visited so far
+    fn fuzzy_prefix_visit<'a>(
+        element: &'a Element<T>,
+        levenshtein: &mut Levenshtein<'_, O>,
+        mut best_distance: usize,
+        results: &mut Vec<(String, &'a T, usize)>,
+    ) {
+        let depth = levenshtein.depth();
+        let parent_label_len = levenshtein.label.len();
+        for c in element.label().chars() {
+            levenshtein.push(c);
+            best_distance = best_distance.min(levenshtein.distance());
+            let min_distance = levenshtein.min_distance();
+            if min_distance >= best_distance || min_distance > levenshtein.max_distance {
+                // the longer prefixes cannot be closer, so the whole subtree shares the best distance
+                if best_distance <= levenshtein.max_distance {
+                    let parent_label = levenshtein.label[..parent_label_len].to_owned();
+                    let subtree = Iter::new(parent_label, std::slice::from_ref(element));
+                    results.extend(subtree.map(|(label, value)| (label, value, best_distance)));
+                }
+                levenshtein.truncate(depth);
+                return;
+            }
+        }
+        if let Some(value) = element.value() {
+            if best_distance <= levenshtein.max_distance {
+                let key = element.key(&levenshtein.label).to_owned();
+                results.push((key, value, best_distance));
+            }
+        }
+        for child in element.children() {
+            Self::fuzzy_prefix_visit(child, levenshtein, best_distance, results);
+        }
+        levenshtein.truncate(depth);
+    }
+}
+
+/// The rows of the Levenshtein distance table between the query and the label being visited,
+/// one row for each char of the label
+pub(crate) struct Levenshtein<'o, O> {
+    query: Vec<char>,
+    pub(crate) max_distance: usize,
+    ordering: &'o O,
+    /// The rows joined in one vec, each row has the length of the query plus one
+    rows: Vec<usize>,
+    pub(crate) label: String,
+}
+
+impl<'o, O: KeyOrdering> Levenshtein<'o, O> {
+    pub(crate) fn new(query: &str, max_distance: usize, ordering: &'o O) -> Self {
+        let query = query.chars().collect::<Vec<_>>();
+        let rows = (0..=query.len()).collect();
+        Levenshtein {
+            query,
+            max_distance,
+            ordering,
+            rows,
+            label: String::new(),
+        }
+    }
+
+    /// Returns the number of chars in the label
+    pub(crate) fn depth(&self) -> usize {
+        self.rows.len() / (self.query.len() + 1) - 1
+    }
+
+    /// Returns the edit distance between the query and the label
+    pub(crate) fn distance(&self) -> usize {
+        self.rows[self.rows.len() - 1]
+    }
+
+    /// Returns the edit distance between the query and the label,
+    /// or any label starting with it
+    pub(crate) fn min_distance(&self) -> usize {
+        let width = self.query.len() + 1;
+        let row = &self.rows[self.rows.len() - width..];
+        row.iter().copied().min().unwrap_or(0)
+    }
+
+    /// Appends the char to the label and computes its row.
+    /// Returns false if no label starting with the new one can be within the maximum distance
+    pub(crate) fn push(&mut self, c: char) -> bool {
+        let width = self.query.len() + 1;
+        let previous = self.rows.len() - width;
+        self.rows.push(self.rows[previous] + 1);
+        for (index, unit) in self.query.iter().enumerate() {
+            let substitution = match self.ordering.compare(unit, &c) {
+                Ordering::Equal => 0,
+                _ => 1,
+            };
+            let current = self.rows.len();
+            let cost = (self.rows[previous + index] + substitution)
+                .min(self.rows[previous + index + 1] + 1)
+                .min(self.rows[current - 1] + 1);
+            self.rows.push(cost);
+        }
+        self.label.push(c);
+        self.min_distance() <= self.max_distance
+    }
+
+    /// Shortens the label to the given number of chars
+    pub(crate) fn truncate(&mut self, depth: usize) {
+        for _ in depth..self.depth() {
+            self.label.pop();
+        }
+        let width = self.query.len() + 1;
+        self.rows.truncate((depth + 1) * width);
+    }
+}
+
+#[cfg(test)]
+mod fuzzy_tests {
+    use crate::ordering::CaseInsensitive;
+    use crate::trie::RadixTrie;
+
+    fn get_test_trie() -> RadixTrie<usize> {
+        let mut trie = RadixTrie::new();
+        let words = ["Won", "Wonder", "Wonderful", "World", "Axes", "Wander"];
+        for word in &words {
+            trie.insert(word, word.len())
+        }
+        trie
+    }
+
+    #[test]
+    fn test_fuzzy_find() {
+        let trie = get_test_trie();
+        let res = trie
+            .fuzzy_find("Wonedr", 3)
+            .into_iter()
+            .map(|(label, _, distance)| (label, distance))
+            .collect::<Vec<_>>();
+        let expected = vec![
+            ("Wander".into(), 3),
+            ("Won".into(), 3),
+            ("Wonder".into(), 2),
+            ("World".into(), 3),
+        ];
+        assert_eq!(res, expected);
+        assert_eq!(trie.fuzzy_find("World", 0), vec![("World".into(), &5, 0)]);
+        assert_eq!(trie.fuzzy_find("Wrld", 0), vec![]);
+        assert_eq!(trie.fuzzy_find("", 3), vec![("Won".into(), &3, 3)]);
+    }
+
+    #[test]
+    fn test_fuzzy_start_with() {
+        let trie = get_test_trie();
+        let res = trie
+            .fuzzy_start_with("Wonedr", 2)
+            .into_iter()
+            .map(|(label, _, distance)| (label, distance))
+            .collect::<Vec<_>>();
+        let expected = vec![("Wonder".into(), 2), ("Wonderful".into(), 2)];
+        assert_eq!(res, expected);
+        let res = trie.fuzzy_start_with("Wa", 0);
+        assert_eq!(res, vec![("Wander".into(), &6, 0)]);
+        assert_eq!(trie.fuzzy_start_with("", 0).len(), 6);
+        assert_eq!(trie.fuzzy_start_with("Xyz", 1), vec![]);
+    }
+
+    #[test]
+    fn test_fuzzy_find_case_insensitive() {
+        let mut trie = RadixTrie::<usize, _>::with_ordering(CaseInsensitive);
+        trie.insert("Won", 1);
+        trie.insert("WONDER", 2);
+        let res = trie.fuzzy_find("wonder", 1);
+        assert_eq!(res, vec![("WONDER".into(), &2, 0)]);
+    }
+}
@@ -0,0 +1,238 @@
+use crate::automaton::Automaton;
+use crate::normalizer::KeyNormalizer;
+use crate::ordering::KeyOrdering;
+use crate::trie::RadixTrie;
+use std::cmp::Ordering;
+
+impl<T, O, N> RadixTrie<T, O, N, str>
+where
+    O: KeyOrdering,
+    N: KeyNormalizer,
+{
+    /// Returns all values with their labels where the labels match the glob pattern,
+    /// in sorted order of the labels.
+    /// In the pattern, `?` matches any char, `*` matches any chars including none,
+    /// `[abc]` or `[a-c]` matches any char in the class, and `[!abc]` matches any char not in it.
+    /// The chars are equal if the ordering of the trie says so
+    /// # Example
+    /// ```rust
+    /// use another_radix_trie::RadixTrie;
+    ///
+    /// let mut trie = RadixTrie::<usize>::new();
+    /// trie.insert("Won", 1);
+    /// trie.insert("Wonder", 2);
+    /// trie.insert("Win", 3);
+    /// trie.insert("Wax", 4);
+    /// let expected = vec![
+    ///     (String::from("Win"), &3),
+    ///     (String::from("Won"), &1),
+    ///     (String::from("Wonder"), &2),
+    /// ];
+    /// assert_eq!(trie.glob("W?n*"), expected);
+    /// ```
+    pub fn glob(&self, pattern: &str) -> Vec<(String, &T)> {
+        let pattern = self.normalize(pattern);
+        self.search(&Glob::new(&pattern, self.ordering()))
+    }
+}
+
+enum Token {
+    /// `?`
+    AnyChar,
+    /// `*`
+    AnyChars,
+    Char(char),
+    /// `[...]`, with the inclusive ranges of chars in the class
+    Class {
+        negated: bool,
+        ranges: Vec<(char, char)>,
+    },
+}
+
+/// A compiled glob pattern.
+/// The states are the positions in the pattern reachable after the chars matched so far,
+/// the pattern matches once its end is reachable
+struct Glob<'o, O> {
+    tokens: Vec<Token>,
+    ordering: &'o O,
+}
+
+impl<'o, O: KeyOrdering> Glob<'o, O> {
+    fn new(pattern: &str, ordering: &'o O) -> Self {
+        let mut tokens = vec![];
+        let mut chars = pattern.chars();
+        while let Some(c) = chars.next() {
+            let token = match c {
+                '?' => Token::AnyChar,
+                '*' => Token::AnyChars,
+                '[' => match Self::parse_class(&mut chars.clone()) {
+                    Some((token, len)) => {
+                        chars.nth(len - 1);
+                        token
+                    }
+                    // an unclosed bracket is matched literally
+                    None => Token::Char('['),
+                },
+                c => Token::Char(c),
+            };
+            tokens.push(token);
+        }
+        Glob { tokens, ordering }
+    }
+
+    /// Parses the class after the opening bracket.
+    /// Returns the class and the number of chars it takes including the closing bracket
+    fn parse_class(chars: &mut impl Iterator<Item = char>) -> Option<(Token, usize)> {
+        let mut class = vec![];
+        let mut len = 0;
+        for c in chars {
+            len += 1;
+            // a closing bracket right after the opening one is a member of the class
+            if c == ']' && !(class.is_empty() || class == ['!'] || class == ['^']) {
+                break;
+            }
+            class.push(c);
+        }
+        if len == class.len() {
+            return None;
+        }
+        let negated = matches!(class.first(), Some('!') | Some('^'));
+        let members = if negated { &class[1..] } else { &class[..] };
+        let mut ranges = vec![];
+        let mut index = 0;
+        while index < members.len() {
+            if index + 2 < members.len() && members[index + 1] == '-' {
+                ranges.push((members[index], members[index + 2]));
+                index += 3;
+            } else {
+                ranges.push((members[index], members[index]));
+                index += 1;
+            }
+        }
+        Some((Token::Class { negated, ranges }, len))
+    }
+
+    /// `*` matches no chars as well, so the position after it is reachable along with it
+    fn skip_any_chars(&self, states: &mut [bool]) {
+        for (index, token) in self.tokens.iter().enumerate() {
+            if states[index] && matches!(token, Token::AnyChars) {
+                states[index + 1] = true;
+            }
+        }
+    }
+
+    fn matches(&self, token: &Token, c: char) -> bool {
+        match token {
+            Token::AnyChar => true,
+            Token::AnyChars => false,
+            Token::Char(expected) => self.ordering.compare(expected, &c) == Ordering::Equal,
+            Token::Class { negated, ranges } => {
+                let in_class = ranges.iter().any(|(low, high)| {
+                    self.ordering.compare(low, &c) != Ordering::Greater
+                        && self.ordering.compare(&c, high) != Ordering::Greater
+                });
+                in_class != *negated
+            }
+        }
+    }
+}
+
+impl<O: KeyOrdering> Automaton for Glob<'_, O> {
+    type State = Vec<bool>;
+
+    fn start(&self) -> Vec<bool> {
+        let mut states = vec![false; self.tokens.len() + 1];
+        states[0] = true;
+        self.skip_any_chars(&mut states);
+        states
+    }
+
+    fn accept(&self, states: &Vec<bool>, c: char) -> Vec<bool> {
+        let mut next = vec![false; states.len()];
+        for (index, token) in self.tokens.iter().enumerate() {
+            if !states[index] {
+                continue;
+            }
+            match token {
+                Token::AnyChars => next[index] = true,
+                token if self.matches(token, c) => next[index + 1] = true,
+                _ => {}
+            }
+        }
+        self.skip_any_chars(&mut next);
+        next
+    }
+
+    fn is_match(&self, states: &Vec<bool>) -> bool {
+        states[self.tokens.len()]
+    }
+
+    fn can_match(&self, states: &Vec<bool>) -> bool {
+        states.contains(&true)
+    }
+}
+
+#[cfg(test)]
+mod glob_tests {
+    use crate::ordering::{CaseInsensitive, KeyOrdering};
+    use crate::trie::RadixTrie;
+
+    fn glob_labels(trie: &RadixTrie<usize, impl KeyOrdering>, pattern: &str) -> Vec<String> {
+        trie.glob(pattern)
+            .into_iter()
+            .map(|(label, _)| label)
+            .collect()
+    }
+
+    fn get_test_trie() -> RadixTrie<usize> {
+        let mut trie = RadixTrie::new();
+        let words = [
+            "Won",
+            "Wonder",
+            "Wonderful",
+            "World",
+            "Axes",
+            "abce",
+            "abde",
+            "abee",
+        ];
+        for word in &words {
+            trie.insert(word, word.len())
+        }
+        trie
+    }
+
+    #[test]
+    fn test_glob_wildcards() {
+        let trie = get_test_trie();
+        assert_eq!(
+            glob_labels(&trie, "W?n*"),
+            vec!["Won", "Wonder", "Wonderful"]
+        );
+        assert_eq!(glob_labels(&trie, "*der*"), vec!["Wonder", "Wonderful"]);
+        assert_eq!(glob_labels(&trie, "W*l"), vec!["Wonderful"]);
+        assert_eq!(glob_labels(&trie, "W???"), Vec::<String>::new());
+        assert_eq!(glob_labels(&trie, "*").len(), 8);
+        assert_eq!(glob_labels(&trie, "Won"), vec!["Won"]);
+        assert_eq!(glob_labels(&trie, ""), Vec::<String>::new());
+    }
+
+    #[test]
+    fn test_glob_classes() {
+        let trie = get_test_trie();
+        assert_eq!(glob_labels(&trie, "ab[cd]e"), vec!["abce", "abde"]);
+        assert_eq!(glob_labels(&trie, "ab[!cd]e"), vec!["abee"]);
+        assert_eq!(glob_labels(&trie, "ab[c-e]e").len(), 3);
+        assert_eq!(glob_labels(&trie, "[A-Z]*s"), vec!["Axes"]);
+        assert_eq!(glob_labels(&trie, "ab[cd"), Vec::<String>::new());
+    }
+
+    #[test]
+    fn test_glob_case_insensitive() {
+        let mut trie = RadixTrie::<usize, _>::with_ordering(CaseInsensitive);
+        trie.insert("Won", 1);
+        trie.insert("world", 2);
+        assert_eq!(glob_labels(&trie, "w?[N]"), vec!["Won"]);
+        assert_eq!(glob_labels(&trie, "W*D"), vec!["world"]);
+    }
+}
@@ -0,0 +1,214 @@
+use crate::element::Element;
+use crate::key::TrieKey;
+use std::borrow::Borrow;
+use std::iter::FusedIterator;
+use std::slice;
+
+/// An iterator over the labels and values of a trie, in sorted order.
+///
+/// The iterator walks the elements lazily from both ends,
+/// so `.rev()` starts from the last sorted child without collecting the trie first.
+///
+/// # Example
+/// ```rust
+/// use another_radix_trie::RadixTrie;
+///
+/// let mut trie = RadixTrie::<usize>::new();
+/// trie.insert("lab", 3);
+/// trie.insert("label", 5);
+/// trie.insert("fox", 3);
+/// let keys = trie.iter().rev().map(|(label, _)| label).collect::<Vec<_>>();
+/// assert_eq!(keys, vec!["label", "lab", "fox"]);
+/// ```
+pub struct Iter<'a, T, K: ?Sized + TrieKey = str> {
+    front: Vec<Frame<'a, T, K>>,
+    front_label: K::Owned,
+    back: Vec<Frame<'a, T, K>>,
+    back_label: K::Owned,
+    /// The last elements yielded from each end. Once they meet the iteration is over
+    front_last: Option<&'a Element<T, K>>,
+    back_last: Option<&'a Element<T, K>>,
+}
+
+/// A level of the traversal: the siblings yet to be visited,
+/// and the element owning them
+struct Frame<'a, T, K: ?Sized + TrieKey> {
+    siblings: slice::Iter<'a, Element<T, K>>,
+    /// Length of the joined parent labels of the siblings
+    prefix_len: usize,
+    parent: Option<&'a Element<T, K>>,
+}
+
+impl<'a, T, K: ?Sized + TrieKey> Iter<'a, T, K> {
+    /// Construct an iterator over the given elements and all their descendants.
+    /// The prefix is the joined label of all the ancestors of the elements
+    pub(crate) fn new(prefix: K::Owned, elements: &'a [Element<T, K>]) -> Self {
+        let prefix_len = prefix.borrow().len();
+        Iter {
+            front: vec![Frame::new(elements, prefix_len, None)],
+            front_label: prefix.borrow().to_owned(),
+            back: vec![Frame::new(elements, prefix_len, None)],
+            back_label: prefix,
+            front_last: None,
+            back_last: None,
+        }
+    }
+
+    /// An iterator that yields nothing
+    pub(crate) fn empty() -> Self {
+        Self::new(K::empty_owned(), &[])
+    }
+
+    /// Checks if the element is already yielded from the other end,
+    /// and stops the iteration if so
+    fn meets(&mut self, element: &Element<T, K>, other_last: Option<&Element<T, K>>) -> bool {
+        let met = other_last.is_some_and(|last| std::ptr::eq(last, element));
+        if met {
+            self.front.clear();
+            self.back.clear();
+        }
+        met
+    }
+}
+
+impl<'a, T, K: ?Sized + TrieKey> Frame<'a, T, K> {
+    fn new(
+        siblings: &'a [Element<T, K>],
+        prefix_len: usize,
+        parent: Option<&'a Element<T, K>>,
+    ) -> Self {
+        Frame {
+            siblings: siblings.iter(),
+            prefix_len,
+            parent,
+        }
+    }
+}
+
+impl<'a, T, K: ?Sized + TrieKey> Iterator for Iter<'a, T, K> {
+    type Item = (K::Owned, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // pre-order: an element is yielded before its children
+        loop {
+            let frame = self.front.last_mut()?;
+            let element = match frame.siblings.next() {
+                Some(element) => element,
+                None => {
+                    self.front.pop();
+                    continue;
+                }
+            };
+            K::truncate_owned(&mut self.front_label, frame.prefix_len);
+            K::push_owned(&mut self.front_label, element.label());
+            let label_len = self.front_label.borrow().len();
+            let frame = Frame::new(element.children(), label_len, Some(element));
+            self.front.push(frame);
+            if let Some(value) = element.value() {
+                if self.meets(element, self.back_last) {
+                    return None;
+                }
+                self.front_last = Some(element);
+                return Some((element.key(self.front_label.borrow()).to_owned(), value));
+            }
+        }
+    }
+}
+
+impl<'a, T, K: ?Sized + TrieKey> DoubleEndedIterator for Iter<'a, T, K> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        // reversed pre-order: an element is yielded after its children, from the last child
+        loop {
+            let frame = self.back.last_mut()?;
+            if let Some(element) = frame.siblings.next_back() {
+                K::truncate_owned(&mut self.back_label, frame.prefix_len);
+                K::push_owned(&mut self.back_label, element.label());
+                let label_len = self.back_label.borrow().len();
+                let frame = Frame::new(element.children(), label_len, Some(element));
+                self.back.push(frame);
+                continue;
+            }
+            let frame = self.back.pop()?;
+            let element = match frame.parent {
+                Some(element) => element,
+                None => continue,
+            };
+            if let Some(value) = element.value() {
+                if self.meets(element, self.front_last) {
+                    return None;
+                }
+                self.back_last = Some(element);
+                let (label, _) = self.back_label.borrow().split_at(frame.prefix_len);
+                return Some((element.key(label).to_owned(), value));
+            }
+        }
+    }
+}
+
+impl<'a, T, K: ?Sized + TrieKey> FusedIterator for Iter<'a, T, K> {}
+
+#[cfg(test)]
+mod iter_tests {
+    use crate::RadixTrie;
+
+    fn get_test_trie() -> RadixTrie<usize> {
+        let mut trie = RadixTrie::<usize>::new();
+        let words = ["Won", "Wonder", "Wonderful", "World", "Axes"];
+        for word in &words {
+            trie.insert(word, word.len())
+        }
+        trie
+    }
+
+    #[test]
+    fn test_iter_sorted() {
+        let trie = get_test_trie();
+        let res = trie.iter().map(|(label, _)| label).collect::<Vec<_>>();
+        assert_eq!(res, vec!["Axes", "Won", "Wonder", "Wonderful", "World"]);
+    }
+
+    #[test]
+    fn test_iter_rev() {
+        let trie = get_test_trie();
+        let res = trie.iter().rev().collect::<Vec<_>>();
+        let expected: Vec<(String, &usize)> = vec![
+            ("World".into(), &5),
+            ("Wonderful".into(), &9),
+            ("Wonder".into(), &6),
+            ("Won".into(), &3),
+            ("Axes".into(), &4),
+        ];
+        assert_eq!(res, expected);
+    }
+
+    #[test]
+    fn test_iter_both_ends_meet() {
+        let trie = get_test_trie();
+        let mut iter = trie.iter();
+        assert_eq!(iter.next().map(|(label, _)| label), Some("Axes".into()));
+        assert_eq!(
+            iter.next_back().map(|(label, _)| label),
+            Some("World".into())
+        );
+        assert_eq!(iter.next().map(|(label, _)| label), Some("Won".into()));
+        assert_eq!(
+            iter.next_back().map(|(label, _)| label),
+            Some("Wonderful".into())
+        );
+        assert_eq!(iter.next().map(|(label, _)| label), Some("Wonder".into()));
+        assert_eq!(iter.next_back(), None);
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn test_prefix_iter_rev() {
+        let trie = get_test_trie();
+        let res = trie
+            .prefix_iter("Wond")
+            .rev()
+            .map(|(label, _)| label)
+            .collect::<Vec<_>>();
+        assert_eq!(res, vec!["Wonderful", "Wonder"]);
+        assert_eq!(trie.prefix_iter("Wonders").next(), None);
+    }
+}
@@ -0,0 +1,209 @@
+use crate::ordering::KeyOrdering;
+use std::cmp::Ordering;
+
+/// The type of the keys a trie can be built on.
+/// A key is a sequence of units, and the labels of a trie are the owned forms of the keys.
+///
+/// It is implemented for `str`, whose units are the chars,
+/// and for slices like `[u8]`, `[u16]` or `[&str]`, whose units are the elements.
+///
+/// # Example
+/// ```rust
+/// use another_radix_trie::{Identity, Lexicographic, RadixTrie};
+///
+/// let mut trie = RadixTrie::<usize, Lexicographic, Identity, [&str]>::default();
+/// trie.insert(&["new", "york"], 1);
+/// trie.insert(&["new", "jersey"], 2);
+/// assert_eq!(trie.find(&["new", "york"]), Some(&1));
+/// assert_eq!(trie.start_with(&["new"]).len(), 2);
+/// ```
+pub trait TrieKey: ToOwned {
+    /// The unit of the keys, which is compared by the `KeyOrdering` of a trie
+    type Unit;
+
+    /// Returns the length of the key, measured the same way as the positions to split at
+    fn len(&self) -> usize;
+
+    /// Returns true if the key has no units
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Divides the key into two at a position, which must be at the boundary of the units
+    fn split_at(&self, mid: usize) -> (&Self, &Self);
+
+    /// Compares the first units of two non-empty keys
+    fn cmp_first_unit<O: KeyOrdering<Self::Unit>>(&self, other: &Self, ordering: &O) -> Ordering;
+
+    /// Returns the length of the longest shared prefix of the two keys, measured in each of them.
+    /// The lengths differ only if the ordering treats different units as equal
+    fn shared_prefix_len<O: KeyOrdering<Self::Unit>>(
+        &self,
+        other: &Self,
+        ordering: &O,
+    ) -> (usize, usize);
+
+    /// Returns the length of the longest prefix the two keys share unit by unit,
+    /// regardless of any ordering
+    fn identical_prefix_len(&self, other: &Self) -> usize;
+
+    /// Returns an empty owned key
+    fn empty_owned() -> Self::Owned;
+
+    /// Appends the key to the end of the owned key
+    fn push_owned(owned: &mut Self::Owned, key: &Self);
+
+    /// Shortens the owned key to the given length
+    fn truncate_owned(owned: &mut Self::Owned, len: usize);
+
+    /// Removes the prefix with the given length from the owned key
+    fn remove_owned_prefix(owned: &mut Self::Owned, len: usize);
+
+    /// Inserts the prefix to the beginning of the owned key
+    fn insert_owned_prefix(owned: &mut Self::Owned, prefix: &Self);
+}
+
+impl TrieKey for str {
+    type Unit = char;
+
+    fn len(&self) -> usize {
+        str::len(self)
+    }
+
+    fn split_at(&self, mid: usize) -> (&Self, &Self) {
+        str::split_at(self, mid)
+    }
+
+    fn cmp_first_unit<O: KeyOrdering>(&self, other: &Self, ordering: &O) -> Ordering {
+        let char1 = self
+            .chars()
+            .next()
+            .expect("First char called on empty string");
+        let char2 = other
+            .chars()
+            .next()
+            .expect("First char called on empty string");
+        ordering.compare(&char1, &char2)
+    }
+
+    fn shared_prefix_len<O: KeyOrdering>(&self, other: &Self, ordering: &O) -> (usize, usize) {
+        let mut chars1 = self.char_indices();
+        let mut chars2 = other.char_indices();
+        loop {
+            match (chars1.next(), chars2.next()) {
+                (Some((index1, char1)), Some((index2, char2))) => {
+                    if ordering.compare(&char1, &char2) != Ordering::Equal {
+                        return (index1, index2);
+                    }
+                }
+                (Some((index1, _)), None) => return (index1, other.len()),
+                (None, Some((index2, _))) => return (self.len(), index2),
+                (None, None) => return (self.len(), other.len()),
+            }
+        }
+    }
+
+    fn identical_prefix_len(&self, other: &Self) -> usize {
+        let mut len = self
+            .bytes()
+            .zip(other.bytes())
+            .take_while(|(byte1, byte2)| byte1 == byte2)
+            .count();
+        while !self.is_char_boundary(len) {
+            len -= 1;
+        }
+        len
+    }
+
+    fn empty_owned() -> String {
+        String::new()
+    }
+
+    fn push_owned(owned: &mut String, key: &Self) {
+        owned.push_str(key)
+    }
+
+    fn truncate_owned(owned: &mut String, len: usize) {
+        owned.truncate(len)
+    }
+
+    fn remove_owned_prefix(owned: &mut String, len: usize) {
+        owned.replace_range(..len, "")
+    }
+
+    fn insert_owned_prefix(owned: &mut String, prefix: &Self) {
+        owned.insert_str(0, prefix)
+    }
+}
+
+impl<U: Clone + PartialEq> TrieKey for [U] {
+    type Unit = U;
+
+    fn len(&self) -> usize {
+        <[U]>::len(self)
+    }
+
+    fn split_at(&self, mid: usize) -> (&Self, &Self) {
+        <[U]>::split_at(self, mid)
+    }
+
+    fn cmp_first_unit<O: KeyOrdering<U>>(&self, other: &Self, ordering: &O) -> Ordering {
+        ordering.compare(&self[0], &other[0])
+    }
+
+    fn shared_prefix_len<O: KeyOrdering<U>>(&self, other: &Self, ordering: &O) -> (usize, usize) {
+        let shared_len = self
+            .iter()
+            .zip(other)
+            .take_while(|(unit1, unit2)| ordering.compare(unit1, unit2) == Ordering::Equal)
+            .count();
+        (shared_len, shared_len)
+    }
+
+    fn identical_prefix_len(&self, other: &Self) -> usize {
+        self.iter()
+            .zip(other)
+            .take_while(|(unit1, unit2)| unit1 == unit2)
+            .count()
+    }
+
+    fn empty_owned() -> Vec<U> {
+        Vec::new()
+    }
+
+    fn push_owned(owned: &mut Vec<U>, key: &Self) {
+        owned.extend_from_slice(key)
+    }
+
+    fn truncate_owned(owned: &mut Vec<U>, len: usize) {
+        owned.truncate(len)
+    }
+
+    fn remove_owned_prefix(owned: &mut Vec<U>, len: usize) {
+        owned.drain(..len);
+    }
+
+    fn insert_owned_prefix(owned: &mut Vec<U>, prefix: &Self) {
+        owned.splice(..0, prefix.iter().cloned());
+    }
+}
+
+#[cfg(test)]
+mod key_tests {
+    use crate::key::TrieKey;
+    use crate::ordering::Lexicographic;
+
+    #[test]
+    fn test_slice_key() {
+        let k1: &[u16] = &[1, 2, 3, 4];
+        let k2: &[u16] = &[1, 2, 5];
+        assert_eq!(k1.shared_prefix_len(k2, &Lexicographic), (2, 2));
+        assert_eq!(k1.identical_prefix_len(k2), 2);
+        assert_eq!("\u{212A}a".identical_prefix_len("\u{212A}b"), 3);
+        assert_eq!("\u{212A}".identical_prefix_len("\u{212B}"), 0);
+        let mut owned = k1.to_owned();
+        <[u16]>::remove_owned_prefix(&mut owned, 2);
+        <[u16]>::insert_owned_prefix(&mut owned, &[7]);
+        assert_eq!(owned, vec![7, 3, 4]);
+    }
+}
@@ -1,4 +1,38 @@
+mod adaptive;
+mod automaton;
+mod counter;
 mod element;
+mod fuzzy;
+mod glob;
+mod iter;
+mod key;
+mod matcher;
+mod multimap;
+mod normalizer;
+mod ordering;
+mod rank;
+mod segment;
+mod set;
+mod substring;
+mod suffix;
+mod tokenize;
 mod trie;
 mod util;
-pub use trie::RadixTrie;
+mod weighted;
+pub use adaptive::AdaptiveCompleter;
+pub use automaton::Automaton;
+pub use counter::RadixCounter;
+pub use iter::Iter;
+pub use key::TrieKey;
+pub use matcher::{FindIter, MatchKind, Matcher};
+pub use multimap::RadixMultiMap;
+pub use normalizer::{Identity, KeyNormalizer, Nfc, Nfkc, Trim};
+pub use ordering::{Alphabetical, CaseInsensitive, KeyOrdering, Lexicographic};
+pub use rank::Ranking;
+pub use segment::SegmentTrie;
+pub use set::RadixSet;
+pub use substring::SubstringRadixTrie;
+pub use suffix::SuffixRadixTrie;
+pub use tokenize::Token;
+pub use trie::{ByteRadixTrie, RadixTrie};
+pub use weighted::WeightedRadixTrie;
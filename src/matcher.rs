@@ -0,0 +1,408 @@
+use crate::element::Element;
+use crate::normalizer::KeyNormalizer;
+use crate::ordering::KeyOrdering;
+use crate::trie::RadixTrie;
+use std::cmp::Ordering;
+use std::collections::VecDeque;
+
+/// How the occurrences are reported by `Matcher::find_iter`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MatchKind {
+    /// Every occurrence of every label, including the ones overlapping each other.
+    /// The occurrences are ordered by their ends, then from the longest to the shortest
+    Overlapping,
+    /// The occurrences not overlapping each other. Among the overlapping ones,
+    /// the one starting first is reported, and the longest one if they start together
+    LeftmostLongest,
+}
+
+impl<T, O, N> RadixTrie<T, O, N, str>
+where
+    O: KeyOrdering,
+    N: KeyNormalizer,
+{
+    /// Compiles the labels into an Aho–Corasick automaton,
+    /// which finds all occurrences of the labels in a text in one pass.
+    /// The occurrences are reported with `MatchKind::Overlapping`
+    /// # Example
+    /// ```rust
+    /// use another_radix_trie::RadixTrie;
+    ///
+    /// let mut trie = RadixTrie::<usize>::new();
+    /// trie.insert("he", 1);
+    /// trie.insert("she", 2);
+    /// trie.insert("hers", 3);
+    /// let matcher = trie.matcher();
+    /// let res = matcher.find_iter("ushers").collect::<Vec<_>>();
+    /// assert_eq!(res, vec![(1, 4, &2), (2, 4, &1), (2, 6, &3)]);
+    /// ```
+    pub fn matcher(&self) -> Matcher<'_, T, O> {
+        self.matcher_with_kind(MatchKind::Overlapping)
+    }
+
+    /// Compiles the labels into an Aho–Corasick automaton,
+    /// which reports the occurrences in the given kind
+    /// # Example
+    /// ```rust
+    /// use another_radix_trie::{MatchKind, RadixTrie};
+    ///
+    /// let mut trie = RadixTrie::<usize>::new();
+    /// trie.insert("he", 1);
+    /// trie.insert("she", 2);
+    /// trie.insert("hers", 3);
+    /// let matcher = trie.matcher_with_kind(MatchKind::LeftmostLongest);
+    /// let res = matcher.find_iter("ushers").collect::<Vec<_>>();
+    /// assert_eq!(res, vec![(1, 4, &2)]);
+    /// ```
+    pub fn matcher_with_kind(&self, kind: MatchKind) -> Matcher<'_, T, O> {
+        let mut matcher = Matcher {
+            nodes: vec![Node {
+                label: vec![],
+                children: vec![],
+                base: 0,
+            }],
+            states: vec![State {
+                node: 0,
+                offset: 0,
+                depth: 0,
+                value: None,
+                fail: 0,
+                output: None,
+            }],
+            ordering: self.ordering(),
+            kind,
+        };
+        for child in self.root().children() {
+            let index = matcher.add_node(child, 0);
+            matcher.nodes[0].children.push(index);
+        }
+        matcher.link();
+        matcher
+    }
+}
+
+/// An Aho–Corasick automaton over the labels of a trie. Constructed with `RadixTrie::matcher`.
+///
+/// The states are the positions between the chars of the labels of the elements,
+/// so a state is either in the middle of a label with one way to go,
+/// or at the end of a label with the children of the element to go.
+/// Every state has a failure link to the state of its longest proper suffix in the trie.
+/// The chars are equal if the ordering of the trie says so,
+/// and the text is not normalized by the normalizer of the trie
+pub struct Matcher<'a, T, O> {
+    nodes: Vec<Node>,
+    states: Vec<State<'a, T>>,
+    ordering: &'a O,
+    kind: MatchKind,
+}
+
+/// An element of the trie, with the chars of its label
+struct Node {
+    label: Vec<char>,
+    children: Vec<usize>,
+    /// The state after the first char of the label
+    base: usize,
+}
+
+struct State<'a, T> {
+    node: usize,
+    /// The number of chars of the label of the node before the state
+    offset: usize,
+    /// The number of chars from the root to the state
+    depth: usize,
+    /// The value of the label ending at the state
+    value: Option<&'a T>,
+    /// The state of the longest proper suffix
+    fail: usize,
+    /// The state of the longest proper suffix with a value
+    output: Option<usize>,
+}
+
+impl<'a, T, O: KeyOrdering> Matcher<'a, T, O> {
+    /// Returns an iterator over the occurrences of the labels in the text,
+    /// as the byte ranges of the occurrences with the values of the labels
+    pub fn find_iter<'m, 't>(&'m self, text: &'t str) -> FindIter<'m, 't, 'a, T, O> {
+        FindIter {
+            matcher: self,
+            text,
+            position: 0,
+            state: 0,
+            starts: VecDeque::new(),
+            pending: None,
+            candidate: None,
+        }
+    }
+
+    /// Adds the element and its descendants, returns the index of the node of the element
+    fn add_node(&mut self, element: &'a Element<T>, depth: usize) -> usize {
+        let index = self.nodes.len();
+        let label = element.label().chars().collect::<Vec<_>>();
+        let base = self.states.len();
+        for offset in 1..=label.len() {
+            self.states.push(State {
+                node: index,
+                offset,
+                depth: depth + offset,
+                value: None,
+                fail: 0,
+                output: None,
+            });
+        }
+        if let Some(state) = self.states.last_mut() {
+            state.value = element.value();
+        }
+        let depth = depth + label.len();
+        self.nodes.push(Node {
+            label,
+            children: vec![],
+            base,
+        });
+        for child in element.children() {
+            let child_index = self.add_node(child, depth);
+            self.nodes[index].children.push(child_index);
+        }
+        index
+    }
+
+    /// Links the failures and outputs of the states, in the order of their depths
+    fn link(&mut self) {
+        let mut queue = VecDeque::new();
+        queue.push_back(0);
+        while let Some(state) = queue.pop_front() {
+            for (c, next) in self.transitions(state) {
+                let fail = match state {
+                    0 => 0,
+                    _ => self.step(self.states[state].fail, c),
+                };
+                let output = match self.states[fail].value {
+                    Some(_) => Some(fail),
+                    None => self.states[fail].output,
+                };
+                self.states[next].fail = fail;
+                self.states[next].output = output;
+                queue.push_back(next);
+            }
+        }
+    }
+
+    /// Returns the chars leading out of the state with the states they lead to
+    fn transitions(&self, state: usize) -> Vec<(char, usize)> {
+        let State { node, offset, .. } = self.states[state];
+        let node = &self.nodes[node];
+        match node.label.get(offset) {
+            Some(c) => vec![(*c, state + 1)],
+            None => node
+                .children
+                .iter()
+                .map(|child| (self.nodes[*child].label[0], self.nodes[*child].base))
+                .collect(),
+        }
+    }
+
+    /// Returns the state after the char, without following the failure links
+    fn goto(&self, state: usize, c: char) -> Option<usize> {
+        let State { node, offset, .. } = self.states[state];
+        let node = &self.nodes[node];
+        let equals = |unit: &char| self.ordering.compare(unit, &c) == Ordering::Equal;
+        match node.label.get(offset) {
+            Some(unit) if equals(unit) => Some(state + 1),
+            Some(_) => None,
+            None => node
+                .children
+                .iter()
+                .map(|child| &self.nodes[*child])
+                .find(|child| equals(&child.label[0]))
+                .map(|child| child.base),
+        }
+    }
+
+    /// Returns the state after the char, following the failure links until the char is accepted
+    fn step(&self, mut state: usize, c: char) -> usize {
+        loop {
+            if let Some(next) = self.goto(state, c) {
+                return next;
+            }
+            if state == 0 {
+                return 0;
+            }
+            state = self.states[state].fail;
+        }
+    }
+}
+
+/// An iterator over the occurrences of the labels in a text. Constructed with `Matcher::find_iter`
+pub struct FindIter<'m, 't, 'a, T, O> {
+    matcher: &'m Matcher<'a, T, O>,
+    text: &'t str,
+    /// The byte offset of the next char to read
+    position: usize,
+    state: usize,
+    /// The byte offsets of the chars from the root to the state
+    starts: VecDeque<usize>,
+    /// The state of the next occurrence ending at the position
+    pending: Option<usize>,
+    /// The leftmost longest occurrence found so far
+    candidate: Option<(usize, usize, &'a T)>,
+}
+
+impl<'a, T, O: KeyOrdering> FindIter<'_, '_, 'a, T, O> {
+    /// Reads the next char, returns false at the end of the text
+    fn advance(&mut self) -> bool {
+        let c = match self.text[self.position..].chars().next() {
+            Some(c) => c,
+            None => return false,
+        };
+        self.starts.push_back(self.position);
+        self.position += c.len_utf8();
+        self.state = self.matcher.step(self.state, c);
+        while self.starts.len() > self.matcher.states[self.state].depth {
+            self.starts.pop_front();
+        }
+        true
+    }
+
+    /// Returns the occurrence of the label of the state ending at the position
+    fn occurrence(&self, state: usize) -> Option<(usize, usize, &'a T)> {
+        let State { depth, value, .. } = self.matcher.states[state];
+        let start = self.starts[self.starts.len() - depth];
+        value.map(|value| (start, self.position, value))
+    }
+
+    /// Returns the longest occurrence ending at the position
+    fn longest_occurrence(&self) -> Option<(usize, usize, &'a T)> {
+        let state = &self.matcher.states[self.state];
+        match state.value {
+            Some(_) => self.occurrence(self.state),
+            None => self.occurrence(state.output?),
+        }
+    }
+
+    /// Reports the candidate, and starts over after it
+    fn take_candidate(&mut self) -> Option<(usize, usize, &'a T)> {
+        let candidate = self.candidate.take()?;
+        self.position = candidate.1;
+        self.state = 0;
+        self.starts.clear();
+        Some(candidate)
+    }
+
+    fn next_overlapping(&mut self) -> Option<(usize, usize, &'a T)> {
+        loop {
+            if let Some(state) = self.pending {
+                self.pending = self.matcher.states[state].output;
+                return self.occurrence(state);
+            }
+            if !self.advance() {
+                return None;
+            }
+            let state = &self.matcher.states[self.state];
+            self.pending = match state.value {
+                Some(_) => Some(self.state),
+                None => state.output,
+            };
+        }
+    }
+
+    fn next_leftmost_longest(&mut self) -> Option<(usize, usize, &'a T)> {
+        loop {
+            if !self.advance() {
+                return self.take_candidate();
+            }
+            // the occurrences found later cannot start before the chars of the state
+            let earliest = self.starts.front().copied().unwrap_or(self.position);
+            if let Some((start, _, _)) = self.candidate {
+                if earliest > start {
+                    return self.take_candidate();
+                }
+            }
+            if let Some(occurrence) = self.longest_occurrence() {
+                match self.candidate {
+                    Some((start, _, _)) if start < occurrence.0 => {}
+                    _ => self.candidate = Some(occurrence),
+                }
+            }
+        }
+    }
+}
+
+impl<'a, T, O: KeyOrdering> Iterator for FindIter<'_, '_, 'a, T, O> {
+    type Item = (usize, usize, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.matcher.kind {
+            MatchKind::Overlapping => self.next_overlapping(),
+            MatchKind::LeftmostLongest => self.next_leftmost_longest(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod matcher_tests {
+    use crate::matcher::MatchKind;
+    use crate::ordering::CaseInsensitive;
+    use crate::trie::RadixTrie;
+
+    /// Finds the occurrences by trying every label at every char
+    fn brute_force(words: &[&str], text: &str) -> Vec<(usize, usize, usize)> {
+        let mut res = vec![];
+        for (end, c) in text.char_indices() {
+            let end = end + c.len_utf8();
+            let mut found = words
+                .iter()
+                .enumerate()
+                .filter(|(_, word)| text[..end].ends_with(*word))
+                .map(|(index, word)| (end - word.len(), end, index))
+                .collect::<Vec<_>>();
+            found.sort();
+            res.extend(found);
+        }
+        res
+    }
+
+    fn get_test_trie(words: &[&str]) -> RadixTrie<usize> {
+        let mut trie = RadixTrie::new();
+        for (index, word) in words.iter().enumerate() {
+            trie.insert(word, index)
+        }
+        trie
+    }
+
+    #[test]
+    fn test_overlapping() {
+        let words = ["a", "ab", "bab", "bc", "bca", "c", "caa", "多倫多", "倫"];
+        let trie = get_test_trie(&words);
+        let matcher = trie.matcher();
+        for text in &["abccab", "bcaabab", "aaaa", "x多倫多倫多y", "", "cbabcaa"] {
+            let res = matcher
+                .find_iter(text)
+                .map(|(start, end, index)| (start, end, *index))
+                .collect::<Vec<_>>();
+            assert_eq!(res, brute_force(&words, text), "{}", text);
+        }
+    }
+
+    #[test]
+    fn test_leftmost_longest() {
+        let words = ["Won", "Wonder", "Wonderful", "der", "ful", "多倫多", "倫"];
+        let trie = get_test_trie(&words);
+        let matcher = trie.matcher_with_kind(MatchKind::LeftmostLongest);
+        let res = matcher
+            .find_iter("Wonderfu Wonderful Wonde")
+            .collect::<Vec<_>>();
+        assert_eq!(res, vec![(0, 6, &1), (9, 18, &2), (19, 22, &0)]);
+        let res = matcher.find_iter("多倫多倫").collect::<Vec<_>>();
+        assert_eq!(res, vec![(0, 9, &5), (9, 12, &6)]);
+        assert_eq!(matcher.find_iter("derful").count(), 2);
+        assert_eq!(matcher.find_iter("xyz").count(), 0);
+    }
+
+    #[test]
+    fn test_case_insensitive() {
+        let mut trie = RadixTrie::<usize, _>::with_ordering(CaseInsensitive);
+        trie.insert("error", 1);
+        trie.insert("warn", 2);
+        let matcher = trie.matcher();
+        let res = matcher.find_iter("WARN: Error").collect::<Vec<_>>();
+        assert_eq!(res, vec![(0, 4, &2), (6, 11, &1)]);
+    }
+}
@@ -0,0 +1,224 @@
+use crate::key::TrieKey;
+use crate::normalizer::{Identity, KeyNormalizer};
+use crate::ordering::{KeyOrdering, Lexicographic};
+use crate::trie::RadixTrie;
+use std::borrow::Borrow;
+
+/// RadixMultiMap stores multiple values associated with strings.
+/// A label is removed from the map once its last value is removed
+///
+/// # Example
+/// ```rust
+/// use another_radix_trie::RadixMultiMap;
+///
+/// let mut map = RadixMultiMap::<usize>::new();
+/// map.insert("rust", 1);
+/// map.insert("rust", 7);
+/// assert_eq!(map.find("rust"), Some(&[1, 7][..]));
+/// assert!(map.remove_value("rust", &1));
+/// assert!(map.remove_value("rust", &7));
+/// assert_eq!(map.find("rust"), None);
+/// ```
+pub struct RadixMultiMap<T, O = Lexicographic, N = Identity, K: ?Sized + TrieKey = str> {
+    trie: RadixTrie<Vec<T>, O, N, K>,
+}
+
+impl<T, O, N, K> Default for RadixMultiMap<T, O, N, K>
+where
+    K: ?Sized + TrieKey,
+    O: KeyOrdering<K::Unit> + Default,
+    N: KeyNormalizer<K> + Default,
+{
+    fn default() -> Self {
+        Self::with_ordering_and_normalizer(O::default(), N::default())
+    }
+}
+
+impl<T> RadixMultiMap<T> {
+    /// Construct a new map.
+    /// Maps with other key types are constructed with `RadixMultiMap::default`
+    pub fn new() -> Self {
+        Self::with_ordering_and_normalizer(Lexicographic, Identity)
+    }
+}
+
+impl<T, O, K: ?Sized + TrieKey> RadixMultiMap<T, O, Identity, K> {
+    /// Construct a new map whose labels are sorted with the given ordering
+    pub fn with_ordering(ordering: O) -> Self {
+        Self::with_ordering_and_normalizer(ordering, Identity)
+    }
+}
+
+impl<T, N, K: ?Sized + TrieKey> RadixMultiMap<T, Lexicographic, N, K> {
+    /// Construct a new map whose labels are normalized with the given normalizer
+    pub fn with_normalizer(normalizer: N) -> Self {
+        Self::with_ordering_and_normalizer(Lexicographic, normalizer)
+    }
+}
+
+impl<T, O, N, K: ?Sized + TrieKey> RadixMultiMap<T, O, N, K> {
+    /// Construct a new map whose labels are sorted with the given ordering,
+    /// and normalized with the given normalizer
+    pub fn with_ordering_and_normalizer(ordering: O, normalizer: N) -> Self {
+        RadixMultiMap {
+            trie: RadixTrie::with_ordering_and_normalizer(ordering, normalizer),
+        }
+    }
+}
+
+impl<T, O, N, K> RadixMultiMap<T, O, N, K>
+where
+    K: ?Sized + TrieKey,
+    O: KeyOrdering<K::Unit>,
+    N: KeyNormalizer<K>,
+{
+    /// Appends the value to the values associated with the label
+    pub fn insert(&mut self, label: &K, value: T) {
+        if let Some((values, _)) = self.trie.find_or_insert_with(label, Vec::new) {
+            values.push(value)
+        }
+    }
+
+    /// Returns the values associated with the label, in the order of insertion.
+    /// If the label does not exist in the map, return None
+    pub fn find(&self, label: &K) -> Option<&[T]> {
+        self.trie.find(label).map(Vec::as_slice)
+    }
+
+    /// Removes all the values associated with the label.
+    /// If the label does not exist in the map, return None
+    pub fn remove(&mut self, label: &K) -> Option<Vec<T>> {
+        self.trie.remove(label)
+    }
+
+    /// Removes the first value equal to the given one from the values associated with the label,
+    /// and removes the label once it has no values left.
+    /// Returns true if the value was removed
+    /// # Example
+    /// ```rust
+    /// use another_radix_trie::RadixMultiMap;
+    ///
+    /// let mut map = RadixMultiMap::<&str>::new();
+    /// map.insert("word", "doc1");
+    /// map.insert("word", "doc2");
+    /// assert!(map.remove_value("word", &"doc1"));
+    /// assert!(!map.remove_value("word", &"doc1"));
+    /// assert_eq!(map.find("word"), Some(&["doc2"][..]));
+    /// ```
+    pub fn remove_value(&mut self, label: &K, value: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        let values = match self.trie.find_mut(label) {
+            Some(values) => values,
+            None => return false,
+        };
+        let index = match values.iter().position(|existing| existing == value) {
+            Some(index) => index,
+            None => return false,
+        };
+        values.remove(index);
+        if values.is_empty() {
+            self.trie.remove(label);
+        }
+        true
+    }
+
+    /// Returns an iterator over all values with their labels, in sorted order of the labels
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = (K::Owned, &T)> + '_ {
+        Self::flatten(self.trie.iter())
+    }
+
+    /// Returns an iterator over all values with their labels where the labels start with given prefix,
+    /// in sorted order of the labels
+    /// # Example
+    /// ```rust
+    /// use another_radix_trie::RadixMultiMap;
+    ///
+    /// let mut map = RadixMultiMap::<usize>::new();
+    /// map.insert("lab", 1);
+    /// map.insert("label", 2);
+    /// map.insert("lab", 3);
+    /// let res = map.prefix_iter("la").collect::<Vec<_>>();
+    /// let expected = vec![
+    ///     (String::from("lab"), &1),
+    ///     (String::from("lab"), &3),
+    ///     (String::from("label"), &2),
+    /// ];
+    /// assert_eq!(res, expected);
+    /// ```
+    pub fn prefix_iter(&self, prefix: &K) -> impl DoubleEndedIterator<Item = (K::Owned, &T)> + '_ {
+        Self::flatten(self.trie.prefix_iter(prefix))
+    }
+
+    /// Returns the ordering of the map
+    pub(crate) fn ordering(&self) -> &O {
+        self.trie.ordering()
+    }
+
+    /// Pairs each value with its label
+    fn flatten<'a>(
+        iter: impl DoubleEndedIterator<Item = (K::Owned, &'a Vec<T>)> + 'a,
+    ) -> impl DoubleEndedIterator<Item = (K::Owned, &'a T)> + 'a
+    where
+        T: 'a,
+        K::Owned: 'a,
+    {
+        iter.flat_map(|(label, values)| {
+            values
+                .iter()
+                .map(move |value| (Borrow::<K>::borrow(&label).to_owned(), value))
+        })
+    }
+}
+
+#[cfg(test)]
+mod multimap_tests {
+    use crate::multimap::RadixMultiMap;
+
+    #[test]
+    fn test_insert_find_remove() {
+        let mut map = RadixMultiMap::<usize>::new();
+        map.insert("Won", 1);
+        map.insert("Wonder", 2);
+        map.insert("Won", 3);
+        assert_eq!(map.find("Won"), Some(&[1, 3][..]));
+        assert_eq!(map.find("Wo"), None);
+        assert_eq!(map.remove("Won"), Some(vec![1, 3]));
+        assert_eq!(map.find("Won"), None);
+        assert_eq!(map.find("Wonder"), Some(&[2][..]));
+    }
+
+    #[test]
+    fn test_remove_value_with_merge() {
+        let mut map = RadixMultiMap::<usize>::new();
+        map.insert("exe", 3);
+        map.insert("execute", 7);
+        map.insert("exec", 4);
+        map.insert("exec", 5);
+        assert!(map.remove_value("exec", &4));
+        assert!(!map.remove_value("exec", &4));
+        assert!(!map.remove_value("exe", &4));
+        assert!(map.remove_value("exec", &5));
+        assert_eq!(map.find("exec"), None);
+        assert_eq!(map.find("execute"), Some(&[7][..]));
+        let res = map.iter().collect::<Vec<_>>();
+        assert_eq!(res, vec![("exe".into(), &3), ("execute".into(), &7)]);
+    }
+
+    #[test]
+    fn test_prefix_iter_rev() {
+        let mut map = RadixMultiMap::<usize>::new();
+        map.insert("Won", 1);
+        map.insert("Wonder", 2);
+        map.insert("Won", 3);
+        map.insert("Axes", 4);
+        let res = map.prefix_iter("Wo").rev().collect::<Vec<_>>();
+        let expected = vec![
+            ("Wonder".into(), &2),
+            ("Won".into(), &3),
+            ("Won".into(), &1),
+        ];
+        assert_eq!(res, expected);
+    }
+}
@@ -0,0 +1,106 @@
+use std::borrow::{Borrow, Cow};
+use unicode_normalization::{is_nfc_quick, is_nfkc_quick, IsNormalized, UnicodeNormalization};
+
+/// Normalizes the labels given to a trie before they are inserted, found, or removed,
+/// so visually identical labels are mapped to the same key
+///
+/// # Example
+/// ```rust
+/// use another_radix_trie::{Nfc, RadixTrie, Trim};
+///
+/// let mut trie = RadixTrie::<usize, _, _>::with_normalizer((Trim, Nfc));
+/// trie.insert(" caf\u{65}\u{301} ", 5);
+/// assert_eq!(trie.find("caf\u{e9}"), Some(&5));
+/// ```
+pub trait KeyNormalizer<K: ?Sized + ToOwned = str> {
+    /// Normalizes the label, returns the borrowed label if no change is required
+    fn normalize<'a>(&self, label: &'a K) -> Cow<'a, K>;
+}
+
+/// Keeps the labels as they are. This is the default normalizer of a trie
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Identity;
+
+impl<K: ?Sized + ToOwned> KeyNormalizer<K> for Identity {
+    fn normalize<'a>(&self, label: &'a K) -> Cow<'a, K> {
+        Cow::Borrowed(label)
+    }
+}
+
+/// Normalizes the labels into the Unicode Normalization Form C
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Nfc;
+
+impl KeyNormalizer for Nfc {
+    fn normalize<'a>(&self, label: &'a str) -> Cow<'a, str> {
+        match is_nfc_quick(label.chars()) {
+            IsNormalized::Yes => Cow::Borrowed(label),
+            _ => Cow::Owned(label.nfc().collect()),
+        }
+    }
+}
+
+/// Normalizes the labels into the Unicode Normalization Form KC,
+/// which also folds the full width characters into their half width forms
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Nfkc;
+
+impl KeyNormalizer for Nfkc {
+    fn normalize<'a>(&self, label: &'a str) -> Cow<'a, str> {
+        match is_nfkc_quick(label.chars()) {
+            IsNormalized::Yes => Cow::Borrowed(label),
+            _ => Cow::Owned(label.nfkc().collect()),
+        }
+    }
+}
+
+/// Removes the leading and trailing whitespaces of the labels
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Trim;
+
+impl KeyNormalizer for Trim {
+    fn normalize<'a>(&self, label: &'a str) -> Cow<'a, str> {
+        Cow::Borrowed(label.trim())
+    }
+}
+
+/// Applies the first normalizer, then the second one
+impl<K, N1, N2> KeyNormalizer<K> for (N1, N2)
+where
+    K: ?Sized + ToOwned,
+    N1: KeyNormalizer<K>,
+    N2: KeyNormalizer<K>,
+{
+    fn normalize<'a>(&self, label: &'a K) -> Cow<'a, K> {
+        match self.0.normalize(label) {
+            Cow::Borrowed(label) => self.1.normalize(label),
+            Cow::Owned(label) => Cow::Owned(self.1.normalize(label.borrow()).into_owned()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod normalizer_tests {
+    use crate::normalizer::{KeyNormalizer, Nfc, Nfkc, Trim};
+    use std::borrow::Cow;
+
+    #[test]
+    fn test_nfc() {
+        assert_eq!(Nfc.normalize("e\u{301}"), "\u{e9}");
+        assert!(matches!(Nfc.normalize("多倫多"), Cow::Borrowed(_)));
+    }
+
+    #[test]
+    fn test_nfkc_folds_width() {
+        assert_eq!(Nfkc.normalize("ＡＢＣ１"), "ABC1");
+    }
+
+    #[test]
+    fn test_chained() {
+        assert_eq!((Trim, Nfc).normalize("\te\u{301} "), "\u{e9}");
+        assert!(matches!(
+            (Trim, Nfc).normalize(" abc "),
+            Cow::Borrowed("abc")
+        ));
+    }
+}
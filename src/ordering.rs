@@ -0,0 +1,114 @@
+use std::cmp::Ordering;
+
+/// Decides the order of the children in a trie, and thus the order of the iteration.
+/// Units compared as `Ordering::Equal` are treated as the same unit.
+/// The units are the characters for `str` keys, and the elements for slice keys
+///
+/// # Example
+/// ```rust
+/// use another_radix_trie::{KeyOrdering, RadixTrie};
+/// use std::cmp::Ordering;
+///
+/// struct Descending;
+///
+/// impl KeyOrdering for Descending {
+///     fn compare(&self, c1: &char, c2: &char) -> Ordering {
+///         c2.cmp(c1)
+///     }
+/// }
+///
+/// let mut trie = RadixTrie::<(), _>::with_ordering(Descending);
+/// trie.insert("a", ());
+/// trie.insert("b", ());
+/// assert_eq!(trie.iter().next(), Some((String::from("b"), &())));
+/// ```
+pub trait KeyOrdering<U = char> {
+    /// Compares two units
+    fn compare(&self, u1: &U, u2: &U) -> Ordering;
+}
+
+impl<U, F: Fn(&U, &U) -> Ordering> KeyOrdering<U> for F {
+    fn compare(&self, u1: &U, u2: &U) -> Ordering {
+        self(u1, u2)
+    }
+}
+
+/// Orders the units by their natural order, which are the code points for characters.
+/// This is the default ordering of a trie
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Lexicographic;
+
+impl<U: Ord> KeyOrdering<U> for Lexicographic {
+    fn compare(&self, u1: &U, u2: &U) -> Ordering {
+        u1.cmp(u2)
+    }
+}
+
+/// Orders the characters alphabetically regardless of their cases,
+/// the upper case comes first when two characters only differ in cases.
+///
+/// # Example
+/// - `"Apple" < "apple" < "Banana" < "cherry"`
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Alphabetical;
+
+impl KeyOrdering for Alphabetical {
+    fn compare(&self, c1: &char, c2: &char) -> Ordering {
+        c1.to_lowercase()
+            .cmp(c2.to_lowercase())
+            .then_with(|| c1.cmp(c2))
+    }
+}
+
+/// Compares the characters regardless of their cases,
+/// so labels only differ in cases are treated as the same label.
+/// Each label is kept as it was inserted and is returned in that casing,
+/// while inserting a label only differs in cases keeps the casing of the first one.
+///
+/// # Example
+/// ```rust
+/// use another_radix_trie::RadixTrie;
+///
+/// let mut trie = RadixTrie::<usize, _>::case_insensitive();
+/// trie.insert("GitHub", 1);
+/// assert_eq!(trie.find("github"), Some(&1));
+/// assert_eq!(trie.iter().next(), Some((String::from("GitHub"), &1)));
+/// ```
+#[derive(Debug, Default, Clone, Copy)]
+pub struct CaseInsensitive;
+
+impl KeyOrdering for CaseInsensitive {
+    fn compare(&self, c1: &char, c2: &char) -> Ordering {
+        c1.to_lowercase().cmp(c2.to_lowercase())
+    }
+}
+
+/// Byte strings are compared regardless of the cases of ASCII letters
+impl KeyOrdering<u8> for CaseInsensitive {
+    fn compare(&self, b1: &u8, b2: &u8) -> Ordering {
+        b1.to_ascii_lowercase().cmp(&b2.to_ascii_lowercase())
+    }
+}
+
+#[cfg(test)]
+mod ordering_tests {
+    use crate::ordering::{Alphabetical, CaseInsensitive, KeyOrdering};
+    use std::cmp::Ordering;
+
+    #[test]
+    fn test_alphabetical() {
+        assert_eq!(Alphabetical.compare(&'Z', &'a'), Ordering::Greater);
+        assert_eq!(Alphabetical.compare(&'A', &'a'), Ordering::Less);
+        assert_eq!(Alphabetical.compare(&'a', &'B'), Ordering::Less);
+        assert_eq!(Alphabetical.compare(&'é', &'é'), Ordering::Equal);
+    }
+
+    #[test]
+    fn test_case_insensitive() {
+        assert_eq!(CaseInsensitive.compare(&'A', &'a'), Ordering::Equal);
+        assert_eq!(CaseInsensitive.compare(&'Z', &'a'), Ordering::Greater);
+        assert_eq!(CaseInsensitive.compare(&'Σ', &'σ'), Ordering::Equal);
+        assert_eq!(CaseInsensitive.compare(&b'A', &b'a'), Ordering::Equal);
+        assert_eq!(CaseInsensitive.compare(&0xc3, &0xe3), Ordering::Less);
+    }
+}
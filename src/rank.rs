@@ -0,0 +1,442 @@
+use crate::element::Element;
+use crate::key::TrieKey;
+use crate::normalizer::KeyNormalizer;
+use crate::ordering::KeyOrdering;
+use crate::trie::RadixTrie;
+use std::borrow::Borrow;
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+impl<T, O, N, K> RadixTrie<T, O, N, K>
+where
+    K: ?Sized + TrieKey,
+    O: KeyOrdering<K::Unit>,
+    N: KeyNormalizer<K>,
+{
+    /// Returns the k values with the highest scores, along with their labels and scores,
+    /// where the labels start with the given prefix.
+    /// The values are ordered from the highest score to the lowest,
+    /// and the values with the same score are in sorted order of the labels.
+    ///
+    /// This is a full scan: every value under the prefix is scored however small k is,
+    /// as nothing is known about the scores of a subtree before visiting it.
+    /// To skip the subtrees with low scores, use `ranking`, which scores every value once
+    /// and answers each later query without visiting those subtrees
+    /// # Example
+    /// ```rust
+    /// use another_radix_trie::RadixTrie;
+    ///
+    /// let mut trie = RadixTrie::<u32>::new();
+    /// trie.insert("wonder", 10);
+    /// trie.insert("wonderful", 30);
+    /// trie.insert("won", 20);
+    /// trie.insert("world", 40);
+    /// let res = trie.top_k("won", 2, |_, popularity| *popularity);
+    /// let expected = vec![
+    ///     (String::from("wonderful"), &30, 30),
+    ///     (String::from("won"), &20, 20),
+    /// ];
+    /// assert_eq!(res, expected);
+    /// ```
+    pub fn top_k<S, F>(&self, prefix: &K, k: usize, score: F) -> Vec<(K::Owned, &T, S)>
+    where
+        S: Ord,
+        F: Fn(&K, &T) -> S,
+    {
+        let prefix = self.normalize(prefix);
+        let (mut label, path) = match self.find_prefix_path(&prefix) {
+            Some(found) => found,
+            None => return vec![],
+        };
+        let element = path
+            .iter()
+            .fold(self.root(), |element, index| &element.children()[*index]);
+        let mut top = TopK::new(k);
+        visit(element, &score, &mut label, &mut top);
+        top.into_sorted_vec()
+            .into_iter()
+            .map(|(score, (label, value))| (label, value, score))
+            .collect()
+    }
+
+    /// Scores every value once, and caches the highest score under each element,
+    /// so the returned ranking answers top k queries without visiting the subtrees
+    /// whose highest scores cannot make the top k.
+    /// The trie cannot be changed while the ranking is in use
+    /// # Example
+    /// ```rust
+    /// use another_radix_trie::RadixTrie;
+    ///
+    /// let mut trie = RadixTrie::<u32>::new();
+    /// trie.insert("wonder", 10);
+    /// trie.insert("wonderful", 30);
+    /// trie.insert("won", 20);
+    /// let ranking = trie.ranking(|_, popularity| *popularity);
+    /// assert_eq!(ranking.top_k("won", 1), vec![(String::from("wonderful"), &30, 30)]);
+    /// ```
+    pub fn ranking<S, F>(&self, score: F) -> Ranking<'_, T, O, N, K, S, F>
+    where
+        S: Ord + Clone,
+        F: Fn(&K, &T) -> S,
+    {
+        let mut label = K::empty_owned();
+        let bound = Bound::new(self.root(), &score, &mut label);
+        Ranking {
+            trie: self,
+            score,
+            bound,
+        }
+    }
+}
+
+/// The cached highest scores of the subtrees of a trie. Constructed with `RadixTrie::ranking`
+pub struct Ranking<'a, T, O, N, K: ?Sized + TrieKey, S, F> {
+    trie: &'a RadixTrie<T, O, N, K>,
+    score: F,
+    bound: Bound<S>,
+}
+
+impl<'a, T, O, N, K, S, F> Ranking<'a, T, O, N, K, S, F>
+where
+    K: ?Sized + TrieKey,
+    O: KeyOrdering<K::Unit>,
+    N: KeyNormalizer<K>,
+    S: Ord + Clone,
+    F: Fn(&K, &T) -> S,
+{
+    /// Same as `RadixTrie::top_k`, with the subtrees visited best first by their highest scores,
+    /// so the subtrees whose highest scores cannot make the top k are never visited
+    pub fn top_k(&self, prefix: &K, k: usize) -> Vec<(K::Owned, &'a T, S)> {
+        let prefix = self.trie.normalize(prefix);
+        let (label, path) = match self.trie.find_prefix_path(&prefix) {
+            Some(found) => found,
+            None => return vec![],
+        };
+        let mut element = self.trie.root();
+        let mut bound = &self.bound;
+        for index in path {
+            element = &element.children()[index];
+            bound = &bound.children[index];
+        }
+        best_first(element, bound, &self.score, label, k)
+    }
+}
+
+/// The highest score under an element, with the bounds of its children in the same order
+pub(crate) struct Bound<S> {
+    pub(crate) max: Option<S>,
+    pub(crate) children: Vec<Bound<S>>,
+}
+
+impl<S: Ord + Clone> Bound<S> {
+    fn new<T, K, F>(element: &Element<T, K>, score: &F, label: &mut K::Owned) -> Self
+    where
+        K: ?Sized + TrieKey,
+        F: Fn(&K, &T) -> S,
+    {
+        let label_len = Borrow::<K>::borrow(&*label).len();
+        K::push_owned(label, element.label());
+        let mut max = element
+            .value()
+            .map(|value| score(element.key((*label).borrow()), value));
+        let children = element
+            .children()
+            .iter()
+            .map(|child| Bound::new(child, score, label))
+            .collect::<Vec<_>>();
+        for child in &children {
+            if child.max > max {
+                max = child.max.clone();
+            }
+        }
+        K::truncate_owned(label, label_len);
+        Bound { max, children }
+    }
+}
+
+/// Scores the values under the element in sorted order
+fn visit<'a, T, K, S, F>(
+    element: &'a Element<T, K>,
+    score: &F,
+    label: &mut K::Owned,
+    top: &mut TopK<S, (K::Owned, &'a T)>,
+) where
+    K: ?Sized + TrieKey,
+    S: Ord,
+    F: Fn(&K, &T) -> S,
+{
+    let label_len = Borrow::<K>::borrow(&*label).len();
+    K::push_owned(label, element.label());
+    if let Some(value) = element.value() {
+        let key = element.key((*label).borrow());
+        let score = score(key, value);
+        if top.accepts(&score) {
+            top.push(score, (key.to_owned(), value));
+        }
+    }
+    for child in element.children() {
+        visit(child, score, label, top);
+    }
+    K::truncate_owned(label, label_len);
+}
+
+/// Returns the k values with the highest scores under the element, along with their labels and scores,
+/// where the label is the joined label of the ancestors of the element.
+/// The elements are visited best first: the frontier is kept in a heap keyed by the bounds,
+/// and a value is taken once its score is the highest left in the frontier,
+/// so only the elements whose bounds make the top k are visited
+pub(crate) fn best_first<'a, T, K, S, F>(
+    element: &'a Element<T, K>,
+    bound: &Bound<S>,
+    score: F,
+    mut label: K::Owned,
+    k: usize,
+) -> Vec<(K::Owned, &'a T, S)>
+where
+    K: ?Sized + TrieKey,
+    S: Ord + Clone,
+    F: Fn(&K, &T) -> S,
+{
+    let mut found = vec![];
+    let mut frontier = BinaryHeap::new();
+    if let Some(max) = &bound.max {
+        K::push_owned(&mut label, element.label());
+        frontier.push(Frontier {
+            max: max.clone(),
+            path: vec![],
+            label,
+            element,
+            bound: Some(bound),
+        });
+    }
+    while found.len() < k {
+        let item = match frontier.pop() {
+            Some(item) => item,
+            None => break,
+        };
+        let bound = match item.bound {
+            Some(bound) => bound,
+            None => {
+                if let Some(value) = item.element.value() {
+                    found.push((item.label, value, item.max));
+                }
+                continue;
+            }
+        };
+        if let Some(value) = item.element.value() {
+            let key = item.element.key(item.label.borrow());
+            frontier.push(Frontier {
+                max: score(key, value),
+                path: item.path.clone(),
+                label: key.to_owned(),
+                element: item.element,
+                bound: None,
+            });
+        }
+        let children = item.element.children().iter().zip(&bound.children);
+        for (index, (child, child_bound)) in children.enumerate() {
+            if let Some(max) = &child_bound.max {
+                let mut path = item.path.clone();
+                path.push(index);
+                let mut label = Borrow::<K>::borrow(&item.label).to_owned();
+                K::push_owned(&mut label, child.label());
+                frontier.push(Frontier {
+                    max: max.clone(),
+                    path,
+                    label,
+                    element: child,
+                    bound: Some(child_bound),
+                });
+            }
+        }
+    }
+    found
+}
+
+/// An element in the frontier of `best_first`, with the highest score under it.
+/// Elements with higher scores, then earlier in sorted order of the labels, are ordered first
+struct Frontier<'a, 'b, T, K: ?Sized + TrieKey, S> {
+    max: S,
+    /// The indices of the elements among their siblings from where the search starts.
+    /// The value of an element is ordered before its children, as its path is a prefix of theirs
+    path: Vec<usize>,
+    /// The joined label of the element, or the key of its value once only the value is left
+    label: K::Owned,
+    element: &'a Element<T, K>,
+    /// The bound of the element, or None once only its value is left to be taken
+    bound: Option<&'b Bound<S>>,
+}
+
+impl<T, K: ?Sized + TrieKey, S: Ord> PartialEq for Frontier<'_, '_, T, K, S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T, K: ?Sized + TrieKey, S: Ord> Eq for Frontier<'_, '_, T, K, S> {}
+
+impl<T, K: ?Sized + TrieKey, S: Ord> PartialOrd for Frontier<'_, '_, T, K, S> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T, K: ?Sized + TrieKey, S: Ord> Ord for Frontier<'_, '_, T, K, S> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.max
+            .cmp(&other.max)
+            .then_with(|| other.path.cmp(&self.path))
+    }
+}
+
+/// Keeps the k items with the highest scores.
+/// Items with the same score are kept in the order they are pushed
+pub(crate) struct TopK<S, L> {
+    /// The worst item kept is on the top
+    heap: BinaryHeap<Ranked<S, L>>,
+    k: usize,
+    pushed: usize,
+}
+
+impl<S: Ord, L> TopK<S, L> {
+    pub(crate) fn new(k: usize) -> Self {
+        TopK {
+            heap: BinaryHeap::with_capacity(k.saturating_add(1).min(1024)),
+            k,
+            pushed: 0,
+        }
+    }
+
+    /// Returns true if an item with the score pushed next would be kept
+    pub(crate) fn accepts(&self, score: &S) -> bool {
+        if self.heap.len() < self.k {
+            return true;
+        }
+        match self.heap.peek() {
+            Some(worst) => *score > worst.score,
+            None => false,
+        }
+    }
+
+    pub(crate) fn push(&mut self, score: S, item: L) {
+        if self.k == 0 {
+            return;
+        }
+        self.heap.push(Ranked {
+            score,
+            index: self.pushed,
+            item,
+        });
+        self.pushed += 1;
+        if self.heap.len() > self.k {
+            self.heap.pop();
+        }
+    }
+
+    /// Returns the items kept, from the highest score to the lowest
+    pub(crate) fn into_sorted_vec(self) -> Vec<(S, L)> {
+        self.heap
+            .into_sorted_vec()
+            .into_iter()
+            .map(|ranked| (ranked.score, ranked.item))
+            .collect()
+    }
+}
+
+/// An item with its score and the order it was pushed in.
+/// Items with higher scores, then pushed earlier, are ordered first
+struct Ranked<S, L> {
+    score: S,
+    index: usize,
+    item: L,
+}
+
+impl<S: Ord, L> PartialEq for Ranked<S, L> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<S: Ord, L> Eq for Ranked<S, L> {}
+
+impl<S: Ord, L> PartialOrd for Ranked<S, L> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S: Ord, L> Ord for Ranked<S, L> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .score
+            .cmp(&self.score)
+            .then(self.index.cmp(&other.index))
+    }
+}
+
+#[cfg(test)]
+mod rank_tests {
+    use crate::trie::RadixTrie;
+    use std::cell::Cell;
+
+    fn get_test_trie() -> RadixTrie<u32> {
+        let mut trie = RadixTrie::new();
+        let words = [
+            ("Won", 5),
+            ("Wonder", 3),
+            ("Wonderful", 8),
+            ("World", 1),
+            ("Worlds", 8),
+            ("Axes", 2),
+            ("Axe", 9),
+        ];
+        for (word, popularity) in &words {
+            trie.insert(word, *popularity)
+        }
+        trie
+    }
+
+    #[test]
+    fn test_top_k() {
+        let trie = get_test_trie();
+        let labels = |res: Vec<(String, &u32, u32)>| {
+            res.into_iter()
+                .map(|(label, _, _)| label)
+                .collect::<Vec<_>>()
+        };
+        let res = trie.top_k("Wo", 3, |_, popularity| *popularity);
+        assert_eq!(labels(res), vec!["Wonderful", "Worlds", "Won"]);
+        let res = trie.top_k("", 2, |label, _| label.len() as u32);
+        assert_eq!(labels(res), vec!["Wonderful", "Wonder"]);
+        assert_eq!(trie.top_k("Wo", 0, |_, popularity| *popularity), vec![]);
+        assert_eq!(trie.top_k("X", 3, |_, popularity| *popularity), vec![]);
+        assert_eq!(trie.top_k("", 10, |_, popularity| *popularity).len(), 7);
+    }
+
+    #[test]
+    fn test_ranking_matches_top_k() {
+        let trie = get_test_trie();
+        let ranking = trie.ranking(|_, popularity| *popularity);
+        for prefix in &["", "W", "Wo", "Won", "Wonderf", "Worlds", "Ax", "B"] {
+            for k in 0..8 {
+                let expected = trie.top_k(prefix, k, |_, popularity| *popularity);
+                assert_eq!(ranking.top_k(prefix, k), expected, "{} {}", prefix, k);
+            }
+        }
+    }
+
+    #[test]
+    fn test_ranking_skips_subtrees() {
+        let trie = get_test_trie();
+        let scored = Cell::new(0);
+        let ranking = trie.ranking(|_, popularity| {
+            scored.set(scored.get() + 1);
+            *popularity
+        });
+        scored.set(0);
+        let res = ranking.top_k("", 1);
+        assert_eq!(res, vec![("Axe".into(), &9, 9)]);
+        // only "Axe" is scored, the bounds of "s" and "Wo" cannot beat it
+        assert_eq!(scored.get(), 1);
+    }
+}
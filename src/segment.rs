@@ -0,0 +1,253 @@
+use crate::key::TrieKey;
+use crate::normalizer::Identity;
+use crate::ordering::Lexicographic;
+use crate::trie::RadixTrie;
+
+/// SegmentTrie stores values associated with paths, like file paths or URL paths.
+/// Unlike `RadixTrie`, the labels are only split at the boundaries of the segments,
+/// so "/usr/lib" and "/usr/libexec" share the node "/usr" instead of "/usr/lib".
+///
+/// Empty segments are ignored, so "/usr/lib", "usr//lib" and "/usr/lib/" are the same path,
+/// and paths are always returned in the form of "/usr/lib"
+///
+/// # Example
+/// ```rust
+/// use another_radix_trie::SegmentTrie;
+///
+/// let mut trie = SegmentTrie::<usize>::new();
+/// trie.insert("/usr/lib", 1);
+/// trie.insert("/usr/libexec", 2);
+/// // The internal structure of this trie will be
+/// // - "/usr"
+/// //    - "/lib" 1
+/// //    - "/libexec" 2
+/// assert_eq!(trie.descendants("/usr/lib"), vec![]);
+/// ```
+pub struct SegmentTrie<T> {
+    trie: RadixTrie<T, Lexicographic, Identity, [String]>,
+    delimiter: char,
+}
+
+impl<T> Default for SegmentTrie<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> SegmentTrie<T> {
+    /// Construct a new trie with '/' as the delimiter
+    pub fn new() -> Self {
+        Self::with_delimiter('/')
+    }
+
+    /// Construct a new trie with the given delimiter
+    /// # Example
+    /// ```rust
+    /// use another_radix_trie::SegmentTrie;
+    ///
+    /// let mut trie = SegmentTrie::<usize>::with_delimiter('.');
+    /// trie.insert("com.example.www", 1);
+    /// assert_eq!(trie.find(".com.example.www"), Some(&1));
+    /// ```
+    pub fn with_delimiter(delimiter: char) -> Self {
+        SegmentTrie {
+            trie: RadixTrie::default(),
+            delimiter,
+        }
+    }
+
+    /// Insert path and associated value into the trie.
+    /// Values will be override if the path provided is already in the trie
+    pub fn insert(&mut self, path: &str, value: T) {
+        let segments = self.segments(path);
+        self.trie.insert(&segments, value)
+    }
+
+    /// Returns the borrowed value associated with related path
+    pub fn find(&self, path: &str) -> Option<&T> {
+        self.trie.find(&self.segments(path))
+    }
+
+    /// Returns the mutable borrowed value associated with related path
+    pub fn find_mut(&mut self, path: &str) -> Option<&mut T> {
+        let segments = self.segments(path);
+        self.trie.find_mut(&segments)
+    }
+
+    /// Removes the value associated with related path.
+    /// If the provided path does not exist in the trie, return None
+    pub fn remove(&mut self, path: &str) -> Option<T> {
+        let segments = self.segments(path);
+        self.trie.remove(&segments)
+    }
+
+    /// Returns all paths with their values, in sorted order
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = (String, &T)> + '_ {
+        self.trie
+            .iter()
+            .map(move |(segments, value)| (self.join(&segments), value))
+    }
+
+    /// Returns the paths exactly one segment below the given path,
+    /// along with their values if exist. A path without value is a parent of other paths
+    /// # Example
+    /// ```rust
+    /// use another_radix_trie::SegmentTrie;
+    ///
+    /// let mut trie = SegmentTrie::<usize>::new();
+    /// trie.insert("/usr/lib/x86_64", 1);
+    /// trie.insert("/usr/libexec", 2);
+    /// trie.insert("/usr/bin", 3);
+    /// let expected = vec![
+    ///     (String::from("/usr/bin"), Some(&3)),
+    ///     (String::from("/usr/lib"), None),
+    ///     (String::from("/usr/libexec"), Some(&2)),
+    /// ];
+    /// assert_eq!(trie.children_of("/usr"), expected);
+    /// ```
+    pub fn children_of(&self, path: &str) -> Vec<(String, Option<&T>)> {
+        let segments = self.segments(path);
+        let (mut label, element) = match self.trie.find_prefix_element(&segments) {
+            Some(found) => found,
+            None => return vec![],
+        };
+        <[String]>::push_owned(&mut label, element.label());
+        if label.len() > segments.len() {
+            // the element continues below the path, so its next segment is the only child
+            let value = if label.len() == segments.len() + 1 {
+                element.value()
+            } else {
+                None
+            };
+            return vec![(self.join(&label[..=segments.len()]), value)];
+        }
+        element
+            .children()
+            .iter()
+            .map(|child| {
+                label.push(child.label()[0].clone());
+                let path = self.join(&label);
+                label.pop();
+                let value = if child.label().len() == 1 {
+                    child.value()
+                } else {
+                    None
+                };
+                (path, value)
+            })
+            .collect()
+    }
+
+    /// Returns all the paths with values below the given path, in sorted order
+    /// # Example
+    /// ```rust
+    /// use another_radix_trie::SegmentTrie;
+    ///
+    /// let mut trie = SegmentTrie::<usize>::new();
+    /// trie.insert("/usr", 0);
+    /// trie.insert("/usr/lib/x86_64", 1);
+    /// trie.insert("/usr/libexec", 2);
+    /// assert_eq!(trie.descendants("/usr/lib"), vec![(String::from("/usr/lib/x86_64"), &1)]);
+    /// ```
+    pub fn descendants(&self, path: &str) -> Vec<(String, &T)> {
+        let segments = self.segments(path);
+        self.trie
+            .prefix_iter(&segments)
+            .filter(|(label, _)| label.len() > segments.len())
+            .map(|(label, value)| (self.join(&label), value))
+            .collect()
+    }
+
+    fn segments(&self, path: &str) -> Vec<String> {
+        path.split(self.delimiter)
+            .filter(|segment| !segment.is_empty())
+            .map(String::from)
+            .collect()
+    }
+
+    fn join(&self, segments: &[String]) -> String {
+        let mut path = String::new();
+        for segment in segments {
+            path.push(self.delimiter);
+            path.push_str(segment);
+        }
+        path
+    }
+}
+
+#[cfg(test)]
+mod segment_tests {
+    use crate::segment::SegmentTrie;
+
+    fn get_test_trie() -> SegmentTrie<usize> {
+        let mut trie = SegmentTrie::<usize>::new();
+        let paths = [
+            "/usr/lib",
+            "/usr/libexec",
+            "/usr/lib/x86_64/libc.so",
+            "/usr/bin/cargo",
+            "/etc",
+        ];
+        for path in &paths {
+            trie.insert(path, path.len())
+        }
+        trie
+    }
+
+    #[test]
+    fn test_insert_find_remove() {
+        let mut trie = get_test_trie();
+        assert_eq!(trie.find("/usr/lib"), Some(&8));
+        assert_eq!(trie.find("usr//lib/"), Some(&8));
+        assert_eq!(trie.find("/usr/li"), None);
+        assert_eq!(trie.find("/usr"), None);
+        assert_eq!(trie.remove("/usr/lib"), Some(8));
+        assert_eq!(trie.find("/usr/lib/x86_64/libc.so"), Some(&23));
+        assert_eq!(trie.remove("/usr/lib"), None);
+    }
+
+    #[test]
+    fn test_children_of() {
+        let trie = get_test_trie();
+        let children = |path| {
+            trie.children_of(path)
+                .into_iter()
+                .map(|(path, value)| (path, value.cloned()))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            children("/"),
+            vec![("/etc".into(), Some(4)), ("/usr".into(), None)]
+        );
+        assert_eq!(
+            children("/usr"),
+            vec![
+                ("/usr/bin".into(), None),
+                ("/usr/lib".into(), Some(8)),
+                ("/usr/libexec".into(), Some(12)),
+            ]
+        );
+        assert_eq!(children("/usr/lib"), vec![("/usr/lib/x86_64".into(), None)]);
+        assert_eq!(children("/usr/bin/cargo"), vec![]);
+        assert_eq!(children("/var"), vec![]);
+    }
+
+    #[test]
+    fn test_descendants() {
+        let trie = get_test_trie();
+        let res = trie
+            .descendants("/usr")
+            .into_iter()
+            .map(|(path, _)| path)
+            .collect::<Vec<_>>();
+        let expected = vec![
+            "/usr/bin/cargo",
+            "/usr/lib",
+            "/usr/lib/x86_64/libc.so",
+            "/usr/libexec",
+        ];
+        assert_eq!(res, expected);
+        assert_eq!(trie.descendants("/").len(), 5);
+        assert_eq!(trie.descendants("/usr/li"), vec![]);
+    }
+}
@@ -0,0 +1,288 @@
+use crate::iter::Iter;
+use crate::key::TrieKey;
+use crate::normalizer::{Identity, KeyNormalizer};
+use crate::ordering::{KeyOrdering, Lexicographic};
+use crate::trie::RadixTrie;
+use crate::util;
+use std::borrow::Borrow;
+use std::cmp::Ordering;
+use std::iter::Peekable;
+
+/// RadixSet stores strings without associated values
+///
+/// # Example
+/// ```rust
+/// use another_radix_trie::RadixSet;
+///
+/// let mut set = RadixSet::new();
+/// assert!(set.insert("ON"));
+/// assert!(!set.insert("ON"));
+/// assert!(set.contains("ON"));
+/// assert!(set.remove("ON"));
+/// assert!(!set.contains("ON"));
+/// ```
+pub struct RadixSet<O = Lexicographic, N = Identity, K: ?Sized + TrieKey = str> {
+    trie: RadixTrie<(), O, N, K>,
+}
+
+impl<O, N, K> Default for RadixSet<O, N, K>
+where
+    K: ?Sized + TrieKey,
+    O: KeyOrdering<K::Unit> + Default,
+    N: KeyNormalizer<K> + Default,
+{
+    fn default() -> Self {
+        Self::with_ordering_and_normalizer(O::default(), N::default())
+    }
+}
+
+impl RadixSet {
+    /// Construct a new set.
+    /// Sets with other key types are constructed with `RadixSet::default`
+    pub fn new() -> Self {
+        Self::with_ordering_and_normalizer(Lexicographic, Identity)
+    }
+}
+
+impl<O, K: ?Sized + TrieKey> RadixSet<O, Identity, K> {
+    /// Construct a new set whose labels are sorted with the given ordering
+    pub fn with_ordering(ordering: O) -> Self {
+        Self::with_ordering_and_normalizer(ordering, Identity)
+    }
+}
+
+impl<N, K: ?Sized + TrieKey> RadixSet<Lexicographic, N, K> {
+    /// Construct a new set whose labels are normalized with the given normalizer
+    pub fn with_normalizer(normalizer: N) -> Self {
+        Self::with_ordering_and_normalizer(Lexicographic, normalizer)
+    }
+}
+
+impl<O, N, K: ?Sized + TrieKey> RadixSet<O, N, K> {
+    /// Construct a new set whose labels are sorted with the given ordering,
+    /// and normalized with the given normalizer
+    pub fn with_ordering_and_normalizer(ordering: O, normalizer: N) -> Self {
+        RadixSet {
+            trie: RadixTrie::with_ordering_and_normalizer(ordering, normalizer),
+        }
+    }
+}
+
+impl<O, N, K> RadixSet<O, N, K>
+where
+    K: ?Sized + TrieKey,
+    O: KeyOrdering<K::Unit>,
+    N: KeyNormalizer<K>,
+{
+    /// Adds the label to the set.
+    /// Returns true if the label was not in the set
+    pub fn insert(&mut self, label: &K) -> bool {
+        matches!(self.trie.find_or_insert_with(label, || ()), Some((_, true)))
+    }
+
+    /// Returns true if the label is in the set
+    pub fn contains(&self, label: &K) -> bool {
+        self.trie.find(label).is_some()
+    }
+
+    /// Removes the label from the set.
+    /// Returns true if the label was in the set
+    pub fn remove(&mut self, label: &K) -> bool {
+        self.trie.remove(label).is_some()
+    }
+
+    /// Returns an iterator over all labels, in sorted order
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = K::Owned> + '_ {
+        self.trie.iter().map(|(label, _)| label)
+    }
+
+    /// Returns an iterator over all labels starting with the given prefix, in sorted order
+    /// # Example
+    /// ```rust
+    /// use another_radix_trie::RadixSet;
+    ///
+    /// let mut set = RadixSet::new();
+    /// set.insert("lab");
+    /// set.insert("label");
+    /// set.insert("fox");
+    /// assert_eq!(set.prefix_iter("la").collect::<Vec<_>>(), vec!["lab", "label"]);
+    /// ```
+    pub fn prefix_iter(&self, prefix: &K) -> impl DoubleEndedIterator<Item = K::Owned> + '_ {
+        self.trie.prefix_iter(prefix).map(|(label, _)| label)
+    }
+
+    /// Returns the labels in either of the sets, in sorted order
+    /// # Example
+    /// ```rust
+    /// use another_radix_trie::RadixSet;
+    ///
+    /// let mut set1 = RadixSet::new();
+    /// set1.insert("lab");
+    /// set1.insert("label");
+    /// let mut set2 = RadixSet::new();
+    /// set2.insert("label");
+    /// set2.insert("fox");
+    /// assert_eq!(set1.union(&set2).collect::<Vec<_>>(), vec!["fox", "lab", "label"]);
+    /// assert_eq!(set1.intersection(&set2).collect::<Vec<_>>(), vec!["label"]);
+    /// assert_eq!(set1.difference(&set2).collect::<Vec<_>>(), vec!["lab"]);
+    /// assert_eq!(set1.symmetric_difference(&set2).collect::<Vec<_>>(), vec!["fox", "lab"]);
+    /// ```
+    pub fn union<'a>(&'a self, other: &'a Self) -> impl Iterator<Item = K::Owned> + 'a {
+        self.merge(other).map(|(label, _, _)| label)
+    }
+
+    /// Returns the labels in both of the sets, in sorted order
+    pub fn intersection<'a>(&'a self, other: &'a Self) -> impl Iterator<Item = K::Owned> + 'a {
+        self.merge(other)
+            .filter(|(_, in_self, in_other)| *in_self && *in_other)
+            .map(|(label, _, _)| label)
+    }
+
+    /// Returns the labels in this set but not in the other, in sorted order
+    pub fn difference<'a>(&'a self, other: &'a Self) -> impl Iterator<Item = K::Owned> + 'a {
+        self.merge(other)
+            .filter(|(_, _, in_other)| !*in_other)
+            .map(|(label, _, _)| label)
+    }
+
+    /// Returns the labels in either of the sets but not in both, in sorted order
+    pub fn symmetric_difference<'a>(
+        &'a self,
+        other: &'a Self,
+    ) -> impl Iterator<Item = K::Owned> + 'a {
+        self.merge(other)
+            .filter(|(_, in_self, in_other)| in_self != in_other)
+            .map(|(label, _, _)| label)
+    }
+
+    /// Returns true if all the labels of this set are in the other
+    pub fn is_subset(&self, other: &Self) -> bool {
+        self.merge(other).all(|(_, _, in_other)| in_other)
+    }
+
+    /// Returns true if all the labels of the other set are in this set
+    pub fn is_superset(&self, other: &Self) -> bool {
+        other.is_subset(self)
+    }
+
+    /// Returns true if the sets have no labels in common
+    pub fn is_disjoint(&self, other: &Self) -> bool {
+        self.intersection(other).next().is_none()
+    }
+
+    fn merge<'a>(&'a self, other: &'a Self) -> Merge<'a, O, K> {
+        Merge {
+            left: self.trie.iter().peekable(),
+            right: other.trie.iter().peekable(),
+            ordering: self.trie.ordering(),
+        }
+    }
+}
+
+/// Walks two sets together in sorted order.
+/// Yields every label along with whether it is in the left set and the right set
+struct Merge<'a, O, K: ?Sized + TrieKey> {
+    left: Peekable<Iter<'a, (), K>>,
+    right: Peekable<Iter<'a, (), K>>,
+    ordering: &'a O,
+}
+
+impl<'a, O, K> Iterator for Merge<'a, O, K>
+where
+    K: ?Sized + TrieKey,
+    O: KeyOrdering<K::Unit>,
+{
+    type Item = (K::Owned, bool, bool);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let order = match (self.left.peek(), self.right.peek()) {
+            (None, None) => return None,
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (Some((left, _)), Some((right, _))) => {
+                util::compare_keys(left.borrow(), right.borrow(), self.ordering)
+            }
+        };
+        match order {
+            Ordering::Less => self.left.next().map(|(label, _)| (label, true, false)),
+            Ordering::Greater => self.right.next().map(|(label, _)| (label, false, true)),
+            Ordering::Equal => {
+                self.right.next();
+                self.left.next().map(|(label, _)| (label, true, true))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod set_tests {
+    use crate::ordering::CaseInsensitive;
+    use crate::set::RadixSet;
+
+    fn get_test_set(words: &[&str]) -> RadixSet {
+        let mut set = RadixSet::new();
+        for word in words {
+            set.insert(word);
+        }
+        set
+    }
+
+    #[test]
+    fn test_insert_contains_remove() {
+        let mut set = RadixSet::new();
+        assert!(set.insert("Won"));
+        assert!(set.insert("Wonder"));
+        assert!(set.insert("World"));
+        assert!(!set.insert("Won"));
+        assert!(!set.insert(""));
+        assert!(set.contains("Wonder"));
+        assert!(!set.contains("Wo"));
+        assert!(set.remove("Won"));
+        assert!(!set.remove("Won"));
+        assert_eq!(set.iter().collect::<Vec<_>>(), vec!["Wonder", "World"]);
+    }
+
+    #[test]
+    fn test_set_algebra() {
+        let set1 = get_test_set(&["Won", "Wonder", "Wonderful", "Axes"]);
+        let set2 = get_test_set(&["Wonder", "World", "Axes", "Ax"]);
+        let union = set1.union(&set2).collect::<Vec<_>>();
+        let expected = vec!["Ax", "Axes", "Won", "Wonder", "Wonderful", "World"];
+        assert_eq!(union, expected);
+        let intersection = set1.intersection(&set2).collect::<Vec<_>>();
+        assert_eq!(intersection, vec!["Axes", "Wonder"]);
+        let difference = set2.difference(&set1).collect::<Vec<_>>();
+        assert_eq!(difference, vec!["Ax", "World"]);
+        let symmetric_difference = set1.symmetric_difference(&set2).collect::<Vec<_>>();
+        assert_eq!(
+            symmetric_difference,
+            vec!["Ax", "Won", "Wonderful", "World"]
+        );
+    }
+
+    #[test]
+    fn test_set_relations() {
+        let set1 = get_test_set(&["Won", "Wonder"]);
+        let set2 = get_test_set(&["Won", "Wonder", "World"]);
+        let set3 = get_test_set(&["Wo", "Axes"]);
+        assert!(set1.is_subset(&set2));
+        assert!(!set2.is_subset(&set1));
+        assert!(set2.is_superset(&set1));
+        assert!(set1.is_disjoint(&set3));
+        assert!(!set1.is_disjoint(&set2));
+    }
+
+    #[test]
+    fn test_case_insensitive_algebra() {
+        let mut set1 = RadixSet::with_ordering(CaseInsensitive);
+        set1.insert("Apple");
+        set1.insert("banana");
+        let mut set2 = RadixSet::with_ordering(CaseInsensitive);
+        set2.insert("APPLE");
+        set2.insert("Cherry");
+        let intersection = set1.intersection(&set2).collect::<Vec<_>>();
+        assert_eq!(intersection, vec!["Apple"]);
+        let union = set1.union(&set2).collect::<Vec<_>>();
+        assert_eq!(union, vec!["Apple", "banana", "Cherry"]);
+    }
+}
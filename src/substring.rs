@@ -0,0 +1,238 @@
+use crate::multimap::RadixMultiMap;
+use crate::normalizer::{Identity, KeyNormalizer};
+use crate::ordering::{KeyOrdering, Lexicographic};
+use crate::util::{self, Slab};
+
+/// SubstringRadixTrie finds the labels containing a substring.
+/// All suffixes of every label are kept in a generalized suffix trie,
+/// mapping each suffix to the ids of the labels ending with it,
+/// so the labels containing a substring are the ones with a suffix starting with it.
+/// The labels are kept once as they were inserted, and found by their ids from the suffix trie.
+/// The suffix trie takes space quadratic in the length of the labels
+///
+/// # Example
+/// ```rust
+/// use another_radix_trie::SubstringRadixTrie;
+///
+/// let mut trie = SubstringRadixTrie::<usize>::new();
+/// trie.insert("rust", 1);
+/// trie.insert("trust", 2);
+/// trie.insert("trie", 3);
+/// let expected = vec![(String::from("rust"), &1), (String::from("trust"), &2)];
+/// assert_eq!(trie.contains_substring("ust"), expected);
+/// ```
+pub struct SubstringRadixTrie<T, O = Lexicographic, N = Identity> {
+    /// The ids of the labels with the starts of the suffixes, keyed by each suffix of the labels once normalized.
+    /// The suffix starting at zero is the whole label, so the labels are found by their ids from there
+    suffixes: RadixMultiMap<(usize, usize), O>,
+    /// The labels as they were inserted with their values, by their ids
+    entries: Slab<(String, T)>,
+    normalizer: N,
+}
+
+impl<T, O, N> Default for SubstringRadixTrie<T, O, N>
+where
+    O: KeyOrdering + Default,
+    N: KeyNormalizer<str> + Default,
+{
+    fn default() -> Self {
+        Self::with_ordering_and_normalizer(O::default(), N::default())
+    }
+}
+
+impl<T> SubstringRadixTrie<T> {
+    /// Construct a new trie
+    pub fn new() -> Self {
+        Self::with_ordering_and_normalizer(Lexicographic, Identity)
+    }
+}
+
+impl<T, O> SubstringRadixTrie<T, O, Identity> {
+    /// Construct a new trie whose labels are sorted with the given ordering
+    pub fn with_ordering(ordering: O) -> Self {
+        Self::with_ordering_and_normalizer(ordering, Identity)
+    }
+}
+
+impl<T, N> SubstringRadixTrie<T, Lexicographic, N> {
+    /// Construct a new trie whose labels are normalized with the given normalizer
+    pub fn with_normalizer(normalizer: N) -> Self {
+        Self::with_ordering_and_normalizer(Lexicographic, normalizer)
+    }
+}
+
+impl<T, O, N> SubstringRadixTrie<T, O, N> {
+    /// Construct a new trie whose labels are sorted with the given ordering,
+    /// and normalized with the given normalizer
+    pub fn with_ordering_and_normalizer(ordering: O, normalizer: N) -> Self {
+        SubstringRadixTrie {
+            suffixes: RadixMultiMap::with_ordering(ordering),
+            entries: Slab::new(),
+            normalizer,
+        }
+    }
+}
+
+impl<T, O, N> SubstringRadixTrie<T, O, N>
+where
+    O: KeyOrdering,
+    N: KeyNormalizer<str>,
+{
+    /// Insert label and associated value into the trie.
+    /// Values will be override if the label provided is already in the trie
+    pub fn insert(&mut self, label: &str, value: T) {
+        let normalized = self.normalizer.normalize(label);
+        if normalized.is_empty() {
+            return;
+        }
+        if let Some(id) = self.find_id(&normalized) {
+            if let Some((_, old_value)) = self.entries.get_mut(id) {
+                *old_value = value;
+            }
+            return;
+        }
+        let id = self.entries.insert((label.to_owned(), value));
+        for (start, _) in normalized.char_indices() {
+            self.suffixes.insert(&normalized[start..], (id, start));
+        }
+    }
+
+    /// Returns the borrowed value associated with related label
+    pub fn find(&self, label: &str) -> Option<&T> {
+        let id = self.find_id(&self.normalizer.normalize(label))?;
+        let (_, value) = self.entries.get(id)?;
+        Some(value)
+    }
+
+    /// Returns the mutable borrowed value associated with related label
+    pub fn find_mut(&mut self, label: &str) -> Option<&mut T> {
+        let id = self.find_id(&self.normalizer.normalize(label))?;
+        let (_, value) = self.entries.get_mut(id)?;
+        Some(value)
+    }
+
+    /// Removes the value associated with related label.
+    /// If the provided label does not exist in the trie, return None.
+    /// For each suffix of the label, the ids of the labels ending with it are scanned for its id
+    pub fn remove(&mut self, label: &str) -> Option<T> {
+        let normalized = self.normalizer.normalize(label);
+        let id = self.find_id(&normalized)?;
+        for (start, _) in normalized.char_indices() {
+            self.suffixes
+                .remove_value(&normalized[start..], &(id, start));
+        }
+        let (_, value) = self.entries.remove(id)?;
+        Some(value)
+    }
+
+    /// Returns all values with their labels where the labels contain the given substring,
+    /// in sorted order of the labels
+    pub fn contains_substring(&self, substring: &str) -> Vec<(String, &T)> {
+        let mut ids = self
+            .suffixes
+            .prefix_iter(&self.normalizer.normalize(substring))
+            .map(|(_, (id, _))| *id)
+            .collect::<Vec<_>>();
+        ids.sort_unstable();
+        ids.dedup();
+        let mut results = ids
+            .into_iter()
+            .filter_map(|id| self.entries.get(id))
+            .map(|(label, value)| (label.clone(), value))
+            .collect::<Vec<_>>();
+        let ordering = self.suffixes.ordering();
+        results.sort_by(|(label1, _), (label2, _)| {
+            util::compare_keys(label1.as_str(), label2, ordering)
+        });
+        results
+    }
+
+    /// Returns an iterator over all values with their labels, in sorted order
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = (String, &T)> + '_ {
+        self.suffixes
+            .iter()
+            .filter(|(_, (_, start))| *start == 0)
+            .filter_map(move |(_, (id, _))| {
+                let (label, value) = self.entries.get(*id)?;
+                Some((label.clone(), value))
+            })
+    }
+
+    /// Returns the id of the label once normalized, among the labels ending with it
+    fn find_id(&self, normalized: &str) -> Option<usize> {
+        let (id, _) = self
+            .suffixes
+            .find(normalized)?
+            .iter()
+            .find(|(_, start)| *start == 0)?;
+        Some(*id)
+    }
+}
+
+#[cfg(test)]
+mod substring_tests {
+    use crate::normalizer::Trim;
+    use crate::ordering::CaseInsensitive;
+    use crate::substring::SubstringRadixTrie;
+
+    fn get_test_trie() -> SubstringRadixTrie<usize> {
+        let mut trie = SubstringRadixTrie::new();
+        let words = ["banana", "bandana", "ban", "nab", "多倫多"];
+        for (index, word) in words.iter().enumerate() {
+            trie.insert(word, index)
+        }
+        trie
+    }
+
+    fn labels(res: Vec<(String, &usize)>) -> Vec<String> {
+        res.into_iter().map(|(label, _)| label).collect()
+    }
+
+    #[test]
+    fn test_contains_substring() {
+        let trie = get_test_trie();
+        assert_eq!(
+            labels(trie.contains_substring("an")),
+            vec!["ban", "banana", "bandana"]
+        );
+        assert_eq!(
+            labels(trie.contains_substring("ana")),
+            vec!["banana", "bandana"]
+        );
+        assert_eq!(labels(trie.contains_substring("ab")), vec!["nab"]);
+        assert_eq!(trie.contains_substring("倫"), vec![("多倫多".into(), &4)]);
+        assert_eq!(trie.contains_substring("bandanas"), vec![]);
+        assert_eq!(trie.contains_substring("").len(), 5);
+    }
+
+    #[test]
+    fn test_suffixes_in_sync() {
+        let mut trie = get_test_trie();
+        trie.insert("banana", 10);
+        assert_eq!(trie.contains_substring("nan"), vec![("banana".into(), &10)]);
+        assert_eq!(trie.remove("banana"), Some(10));
+        assert_eq!(trie.remove("banana"), None);
+        assert_eq!(trie.contains_substring("nan"), vec![]);
+        assert_eq!(labels(trie.contains_substring("ana")), vec!["bandana"]);
+        assert_eq!(trie.remove("bandana"), Some(1));
+        assert_eq!(labels(trie.contains_substring("a")), vec!["ban", "nab"]);
+        assert_eq!(trie.iter().count(), 3);
+    }
+
+    #[test]
+    fn test_ordering_and_normalizer() {
+        let mut trie = SubstringRadixTrie::with_ordering_and_normalizer(CaseInsensitive, Trim);
+        trie.insert(" Rust ", 1);
+        trie.insert("trust", 2);
+        trie.insert("RUST", 3);
+        assert_eq!(trie.find("rust"), Some(&3));
+        assert_eq!(
+            trie.contains_substring(" US "),
+            vec![(" Rust ".into(), &3), ("trust".into(), &2)]
+        );
+        assert_eq!(trie.contains_substring("rusty"), vec![]);
+        assert_eq!(trie.remove("rusT"), Some(3));
+        assert_eq!(labels(trie.contains_substring("ust")), vec!["trust"]);
+        assert_eq!(trie.iter().count(), 1);
+    }
+}
@@ -0,0 +1,219 @@
+use crate::normalizer::{Identity, KeyNormalizer};
+use crate::ordering::{KeyOrdering, Lexicographic};
+use crate::trie::RadixTrie;
+use crate::util::{self, Slab};
+
+/// SuffixRadixTrie answers suffix queries along with the prefix queries of RadixTrie.
+/// The values are kept apart, and every label refers to its value by an id
+/// both from a trie and reversed from a second trie, kept in sync on insert and remove,
+/// so the labels ending with a suffix are the reversed labels starting with the reversed suffix
+///
+/// # Example
+/// ```rust
+/// use another_radix_trie::SuffixRadixTrie;
+///
+/// let mut trie = SuffixRadixTrie::<usize>::new();
+/// trie.insert("src/lib.rs", 1);
+/// trie.insert("src/trie.rs", 2);
+/// trie.insert("README.md", 3);
+/// let expected = vec![(String::from("src/lib.rs"), &1), (String::from("src/trie.rs"), &2)];
+/// assert_eq!(trie.ends_with(".rs"), expected);
+/// ```
+pub struct SuffixRadixTrie<T, O = Lexicographic, N = Identity> {
+    trie: RadixTrie<usize, O, N>,
+    /// The reversed labels of the trie, once normalized
+    reversed: RadixTrie<usize, O>,
+    values: Slab<T>,
+}
+
+impl<T, O, N> Default for SuffixRadixTrie<T, O, N>
+where
+    O: KeyOrdering + Clone + Default,
+    N: KeyNormalizer<str> + Default,
+{
+    fn default() -> Self {
+        Self::with_ordering_and_normalizer(O::default(), N::default())
+    }
+}
+
+impl<T> SuffixRadixTrie<T> {
+    /// Construct a new trie
+    pub fn new() -> Self {
+        Self::with_ordering_and_normalizer(Lexicographic, Identity)
+    }
+}
+
+impl<T, O: Clone> SuffixRadixTrie<T, O, Identity> {
+    /// Construct a new trie whose labels are sorted with the given ordering
+    pub fn with_ordering(ordering: O) -> Self {
+        Self::with_ordering_and_normalizer(ordering, Identity)
+    }
+}
+
+impl<T, N> SuffixRadixTrie<T, Lexicographic, N> {
+    /// Construct a new trie whose labels are normalized with the given normalizer
+    pub fn with_normalizer(normalizer: N) -> Self {
+        Self::with_ordering_and_normalizer(Lexicographic, normalizer)
+    }
+}
+
+impl<T, O: Clone, N> SuffixRadixTrie<T, O, N> {
+    /// Construct a new trie whose labels are sorted with the given ordering,
+    /// and normalized with the given normalizer
+    pub fn with_ordering_and_normalizer(ordering: O, normalizer: N) -> Self {
+        SuffixRadixTrie {
+            trie: RadixTrie::with_ordering_and_normalizer(ordering.clone(), normalizer),
+            reversed: RadixTrie::with_ordering_and_normalizer(ordering, Identity),
+            values: Slab::new(),
+        }
+    }
+}
+
+impl<T, O, N> SuffixRadixTrie<T, O, N>
+where
+    O: KeyOrdering,
+    N: KeyNormalizer<str>,
+{
+    /// Insert label and associated value into the trie.
+    /// Values will be override if the label provided is already in the trie
+    pub fn insert(&mut self, label: &str, value: T) {
+        let mut value = Some(value);
+        let values = &mut self.values;
+        let found = self
+            .trie
+            .find_or_insert_with(label, || values.insert(value.take().unwrap()));
+        match found {
+            Some((id, false)) => {
+                if let Some(old_value) = values.get_mut(*id) {
+                    *old_value = value.take().unwrap();
+                }
+            }
+            Some((id, true)) => {
+                let id = *id;
+                let reversed = reverse(&self.trie.normalize(label));
+                self.reversed.insert(&reversed, id);
+            }
+            None => {}
+        }
+    }
+
+    /// Returns the borrowed value associated with related label
+    pub fn find(&self, label: &str) -> Option<&T> {
+        self.values.get(*self.trie.find(label)?)
+    }
+
+    /// Returns the mutable borrowed value associated with related label
+    pub fn find_mut(&mut self, label: &str) -> Option<&mut T> {
+        self.values.get_mut(*self.trie.find(label)?)
+    }
+
+    /// Removes the value associated with related label.
+    /// If the provided label does not exist in the trie, return None
+    pub fn remove(&mut self, label: &str) -> Option<T> {
+        let id = self.trie.remove(label)?;
+        self.reversed.remove(&reverse(&self.trie.normalize(label)));
+        self.values.remove(id)
+    }
+
+    /// Returns all values with their labels where the labels start with the given prefix
+    pub fn start_with(&self, prefix: &str) -> Vec<(String, &T)> {
+        self.trie
+            .start_with(prefix)
+            .into_iter()
+            .filter_map(|(label, id)| Some((label, self.values.get(*id)?)))
+            .collect()
+    }
+
+    /// Returns all values with their labels where the labels end with the given suffix,
+    /// in sorted order of the labels
+    pub fn ends_with(&self, suffix: &str) -> Vec<(String, &T)> {
+        let suffix = reverse(&self.trie.normalize(suffix));
+        let mut results = self
+            .reversed
+            .prefix_iter(&suffix)
+            .filter_map(|(reversed, id)| Some((reverse(&reversed), self.values.get(*id)?)))
+            .collect::<Vec<_>>();
+        let ordering = self.trie.ordering();
+        results.sort_by(|(label1, _), (label2, _)| {
+            util::compare_keys(label1.as_str(), label2, ordering)
+        });
+        results
+    }
+
+    /// Returns an iterator over all values with their labels, in sorted order
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = (String, &T)> + '_ {
+        self.trie
+            .iter()
+            .filter_map(move |(label, id)| Some((label, self.values.get(*id)?)))
+    }
+}
+
+fn reverse(label: &str) -> String {
+    label.chars().rev().collect()
+}
+
+#[cfg(test)]
+mod suffix_tests {
+    use crate::normalizer::Nfc;
+    use crate::ordering::CaseInsensitive;
+    use crate::suffix::SuffixRadixTrie;
+
+    fn get_test_trie() -> SuffixRadixTrie<usize> {
+        let mut trie = SuffixRadixTrie::new();
+        let words = ["Wonder", "Wonderful", "Won", "Wander", "Thunder", "多倫多"];
+        for (index, word) in words.iter().enumerate() {
+            trie.insert(word, index)
+        }
+        trie
+    }
+
+    fn labels(res: Vec<(String, &usize)>) -> Vec<String> {
+        res.into_iter().map(|(label, _)| label).collect()
+    }
+
+    #[test]
+    fn test_ends_with() {
+        let trie = get_test_trie();
+        assert_eq!(
+            labels(trie.ends_with("der")),
+            vec!["Thunder", "Wander", "Wonder"]
+        );
+        assert_eq!(labels(trie.ends_with("onder")), vec!["Wonder"]);
+        assert_eq!(trie.ends_with("倫多"), vec![("多倫多".into(), &5)]);
+        assert_eq!(trie.ends_with("Wonderful"), vec![("Wonderful".into(), &1)]);
+        assert_eq!(trie.ends_with("xWon"), vec![]);
+        assert_eq!(trie.ends_with("").len(), 6);
+    }
+
+    #[test]
+    fn test_reversed_in_sync() {
+        let mut trie = get_test_trie();
+        assert_eq!(trie.remove("Wander"), Some(3));
+        assert_eq!(trie.remove("Wander"), None);
+        assert_eq!(labels(trie.ends_with("der")), vec!["Thunder", "Wonder"]);
+        trie.insert("Wonder", 10);
+        *trie.find_mut("Thunder").unwrap() += 10;
+        assert_eq!(
+            trie.ends_with("der"),
+            vec![("Thunder".into(), &14), ("Wonder".into(), &10)]
+        );
+        assert_eq!(labels(trie.start_with("Won")).len(), 3);
+        assert_eq!(trie.iter().count(), 5);
+    }
+
+    #[test]
+    fn test_ends_with_ordering_and_normalizer() {
+        let mut trie = SuffixRadixTrie::with_ordering_and_normalizer(CaseInsensitive, Nfc);
+        // "é" in a composed form, and a decomposed form
+        trie.insert("Caf\u{e9}", 1);
+        trie.insert("ol\u{e9}", 2);
+        trie.insert("Cafe", 3);
+        assert_eq!(
+            trie.ends_with("E\u{301}"),
+            vec![("Caf\u{e9}".into(), &1), ("ol\u{e9}".into(), &2)]
+        );
+        assert_eq!(trie.ends_with("FE"), vec![("Cafe".into(), &3)]);
+        assert_eq!(trie.remove("CAFE\u{301}"), Some(1));
+        assert_eq!(trie.ends_with("\u{e9}"), vec![("ol\u{e9}".into(), &2)]);
+    }
+}
@@ -0,0 +1,234 @@
+use crate::normalizer::KeyNormalizer;
+use crate::ordering::KeyOrdering;
+use crate::trie::RadixTrie;
+
+/// A span of the text split by `RadixTrie::tokenize` or `RadixTrie::segment`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Token<'t, 'a, T> {
+    /// A label of the trie as it is in the text, with its value
+    Known(&'t str, &'a T),
+    /// A char not covered by any label of the trie
+    Unknown(char),
+}
+
+/// A split of the text by `RadixTrie::segment`: the number of unknown chars, the total cost,
+/// and the start of the last span with its value
+type Split<'a, T> = (usize, f64, usize, Option<&'a T>);
+
+impl<T, O, N> RadixTrie<T, O, N, str>
+where
+    O: KeyOrdering,
+    N: KeyNormalizer,
+{
+    /// Splits the text into the longest labels of the trie, from the start to the end.
+    /// At each position, the longest label starting there is taken,
+    /// and a char is taken as unknown if no label starts there.
+    /// The text is not normalized by the normalizer of the trie
+    /// # Example
+    /// ```rust
+    /// use another_radix_trie::{RadixTrie, Token};
+    ///
+    /// let mut trie = RadixTrie::<usize>::new();
+    /// trie.insert("多倫多", 1);
+    /// trie.insert("多", 2);
+    /// let res = trie.tokenize("多倫多多!").collect::<Vec<_>>();
+    /// let expected = vec![Token::Known("多倫多", &1), Token::Known("多", &2), Token::Unknown('!')];
+    /// assert_eq!(res, expected);
+    /// ```
+    pub fn tokenize<'a>(&'a self, text: &'a str) -> impl Iterator<Item = Token<'a, 'a, T>> + 'a {
+        self.tokenize_with(text, |unknown| unknown.chars().next().map(Token::Unknown))
+    }
+
+    /// Same as `tokenize`, but the chars not starting any label are given to the fallback
+    /// as the slices of the text. The token returned by the fallback is taken instead,
+    /// and the char is skipped if the fallback returns None
+    /// # Example
+    /// ```rust
+    /// use another_radix_trie::{RadixTrie, Token};
+    ///
+    /// let mut trie = RadixTrie::<usize>::new();
+    /// trie.insert("Toronto", 1);
+    /// let res = trie
+    ///     .tokenize_with("Toronto, ON", |unknown| match unknown {
+    ///         " " | "," => None,
+    ///         _ => Some(Token::Known(unknown, &0)),
+    ///     })
+    ///     .collect::<Vec<_>>();
+    /// let expected = vec![Token::Known("Toronto", &1), Token::Known("O", &0), Token::Known("N", &0)];
+    /// assert_eq!(res, expected);
+    /// ```
+    pub fn tokenize_with<'a, F>(
+        &'a self,
+        text: &'a str,
+        mut fallback: F,
+    ) -> impl Iterator<Item = Token<'a, 'a, T>> + 'a
+    where
+        F: FnMut(&'a str) -> Option<Token<'a, 'a, T>> + 'a,
+    {
+        let mut rest = text;
+        std::iter::from_fn(move || loop {
+            let c = rest.chars().next()?;
+            if let Some((len, value)) = self.longest_prefix_match(rest) {
+                let (known, next) = rest.split_at(len);
+                rest = next;
+                return Some(Token::Known(known, value));
+            }
+            let (unknown, next) = rest.split_at(c.len_utf8());
+            rest = next;
+            if let Some(token) = fallback(unknown) {
+                return Some(token);
+            }
+        })
+    }
+
+    /// Splits the text into the labels of the trie with the minimum total cost,
+    /// where the cost of each label is given by the cost function with its value,
+    /// like the negative logarithm of the frequency of the label.
+    /// All the labels starting at every position are considered,
+    /// so the text is split the best way even if the longest labels mislead.
+    ///
+    /// The chars not covered by any label are kept as unknown, and cost more than any labels,
+    /// so the split with the fewest unknown chars is taken first.
+    /// The text is not normalized by the normalizer of the trie
+    /// # Example
+    /// ```rust
+    /// use another_radix_trie::{RadixTrie, Token};
+    ///
+    /// let mut trie = RadixTrie::<f64>::new();
+    /// trie.insert("the", 0.05);
+    /// trie.insert("them", 0.01);
+    /// trie.insert("an", 0.03);
+    /// trie.insert("man", 0.02);
+    /// let res = trie.segment("theman", |_, frequency| -frequency.ln());
+    /// assert_eq!(res, vec![Token::Known("the", &0.05), Token::Known("man", &0.02)]);
+    /// ```
+    pub fn segment<'a, F>(&'a self, text: &'a str, cost: F) -> Vec<Token<'a, 'a, T>>
+    where
+        F: Fn(&str, &T) -> f64,
+    {
+        // the best split of the text before each byte offset
+        let mut best: Vec<Option<Split<T>>> = vec![None; text.len() + 1];
+        best[0] = Some((0, 0.0, 0, None));
+        for (start, c) in text.char_indices() {
+            let (unknowns, total, _, _) = match best[start] {
+                Some(split) => split,
+                None => continue,
+            };
+            let rest = &text[start..];
+            let mut relax = |end: usize, split: Split<'a, T>| {
+                let better = match best[end] {
+                    Some((unknowns, total, _, _)) => {
+                        split.0 < unknowns
+                            || split.0 == unknowns && split.1.total_cmp(&total).is_lt()
+                    }
+                    None => true,
+                };
+                if better {
+                    best[end] = Some(split);
+                }
+            };
+            self.prefix_matches(rest, |len, value| {
+                let total = total + cost(&rest[..len], value);
+                relax(start + len, (unknowns, total, start, Some(value)));
+            });
+            relax(start + c.len_utf8(), (unknowns + 1, total, start, None));
+        }
+        let mut spans = vec![];
+        let mut end = text.len();
+        while end > 0 {
+            let (_, _, start, value) = best[end].expect("Every char boundary is reachable");
+            let span = &text[start..end];
+            spans.push(match value {
+                Some(value) => Token::Known(span, value),
+                None => Token::Unknown(span.chars().next().expect("Unknown span is a char")),
+            });
+            end = start;
+        }
+        spans.reverse();
+        spans
+    }
+}
+
+#[cfg(test)]
+mod tokenize_tests {
+    use crate::ordering::CaseInsensitive;
+    use crate::tokenize::Token;
+    use crate::trie::RadixTrie;
+
+    #[test]
+    fn test_tokenize_longest() {
+        let mut trie = RadixTrie::<usize>::new();
+        for (index, word) in ["多倫多", "多", "倫敦", "Won", "Wonder"].iter().enumerate() {
+            trie.insert(word, index);
+        }
+        let res = trie.tokenize("多倫敦多倫多Wonde").collect::<Vec<_>>();
+        let expected = vec![
+            Token::Known("多", &1),
+            Token::Known("倫敦", &2),
+            Token::Known("多倫多", &0),
+            Token::Known("Won", &3),
+            Token::Unknown('d'),
+            Token::Unknown('e'),
+        ];
+        assert_eq!(res, expected);
+        assert_eq!(trie.tokenize("").count(), 0);
+    }
+
+    #[test]
+    fn test_tokenize_case_insensitive() {
+        let mut trie = RadixTrie::<usize, _>::with_ordering(CaseInsensitive);
+        trie.insert("error", 1);
+        trie.insert("warn", 2);
+        let res = trie
+            .tokenize_with("WARN: Error!", |unknown| match unknown.trim() {
+                "" => None,
+                _ => Some(Token::Known(unknown, &0)),
+            })
+            .collect::<Vec<_>>();
+        let expected = vec![
+            Token::Known("WARN", &2),
+            Token::Known(":", &0),
+            Token::Known("Error", &1),
+            Token::Known("!", &0),
+        ];
+        assert_eq!(res, expected);
+    }
+
+    #[test]
+    fn test_segment() {
+        let mut trie = RadixTrie::<f64>::new();
+        let words = [
+            ("the", 0.05),
+            ("them", 0.01),
+            ("an", 0.03),
+            ("man", 0.02),
+            ("ran", 0.01),
+        ];
+        for (word, frequency) in &words {
+            trie.insert(word, *frequency);
+        }
+        let known = |tokens: Vec<Token<f64>>| {
+            tokens
+                .into_iter()
+                .map(|token| match token {
+                    Token::Known(word, _) => word.to_string(),
+                    Token::Unknown(c) => format!("?{}", c),
+                })
+                .collect::<Vec<_>>()
+        };
+        let cost = |_: &str, frequency: &f64| -frequency.ln();
+        // the longest match takes "them" and leaves "anran"
+        assert_eq!(
+            known(trie.tokenize("themanran").collect()),
+            vec!["them", "an", "ran"]
+        );
+        assert_eq!(
+            known(trie.segment("themanran", cost)),
+            vec!["the", "man", "ran"]
+        );
+        // covering more chars is preferred to lower costs
+        assert_eq!(known(trie.segment("xthem", cost)), vec!["?x", "them"]);
+        assert_eq!(known(trie.segment("多ran", cost)), vec!["?多", "ran"]);
+        assert_eq!(trie.segment("", cost), vec![]);
+    }
+}
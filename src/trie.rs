@@ -1,6 +1,12 @@
 use self::FindOutcome::*;
 use crate::element::Element;
+use crate::iter::Iter;
+use crate::key::TrieKey;
+use crate::normalizer::{Identity, KeyNormalizer};
+use crate::ordering::{CaseInsensitive, KeyOrdering, Lexicographic};
 use crate::util;
+use std::borrow::{Borrow, Cow};
+use std::cmp::Ordering;
 
 /// RadixTrie stores values associated with strings
 ///
@@ -14,32 +20,70 @@ use crate::util;
 /// // - "ON" 3
 /// //    - "20" 4
 /// ```
-pub struct RadixTrie<T> {
-    entry: Element<T>,
+///
+/// The children are sorted by the `KeyOrdering` of the trie, which is `Lexicographic` by default.
+/// The labels are normalized by the `KeyNormalizer` of the trie, which is `Identity` by default.
+/// The labels are `str` by default, and can be any other `TrieKey` like `[u8]`
+pub struct RadixTrie<T, O = Lexicographic, N = Identity, K: ?Sized + TrieKey = str> {
+    entry: Element<T, K>,
+    ordering: O,
+    normalizer: N,
+}
+
+/// ByteRadixTrie stores values associated with byte strings, which are not required to be valid UTF-8.
+/// The labels are split at any byte
+///
+/// # Example
+/// ```rust
+/// use another_radix_trie::ByteRadixTrie;
+///
+/// let mut trie = ByteRadixTrie::<usize>::default();
+/// trie.insert(b"\xffON", 3);
+/// trie.insert(b"\xffON20", 4);
+/// assert_eq!(trie.find(b"\xffON"), Some(&3));
+/// assert_eq!(trie.start_with(b"\xff").len(), 2);
+/// ```
+pub type ByteRadixTrie<T, O = Lexicographic, N = Identity> = RadixTrie<T, O, N, [u8]>;
+
+/// How inserting a label changes the structure of the trie,
+/// for the structures mirroring the elements of the trie
+pub(crate) enum Growth {
+    /// The label is already at the end of the path, as a value or a node element
+    Existing,
+    /// A new leaf is inserted at the end of the path
+    Leaf,
+    /// A new value element is inserted at the end of the path,
+    /// with the element previously there moved to be its only child
+    Prefix,
+    /// A new node element is inserted at the second last index of the path,
+    /// with the element previously there moved to be one of its children,
+    /// and a new leaf at the end of the path to be the other
+    Fork,
 }
 
 /// Outcome of a searching with a given label against an entry
-enum FindOutcome<'a> {
+enum FindOutcome<'a, K: ?Sized> {
     /// The given label matches the label of an element.
     /// The index of that element is included
     /// Example:
     /// - given: 'label', find: 'label'
     ExactMatch(usize),
     /// An element has a label that is also a prefix for the given label.
-    /// The index of that element is included
+    /// The index of that element and the length of the matched part of the given label are included
     /// Example:
     /// - given: 'label', find: 'lab'
-    PrefixMatch(usize),
+    PrefixMatch(usize, usize),
     /// The given label is a prefix of an element's label
-    /// The index of that element is included
+    /// The index of that element and the length of the matched part of its label are included
     /// Example:
     /// - given: 'lab', find: 'label'
-    AsPrefixOf(usize),
+    AsPrefixOf(usize, usize),
     /// The given label intersects with an element's label
-    /// The index of that element and the shared common substring are included
+    /// The index of that element, the shared common substring as in the element's label,
+    /// and the length of the shared common substring in the given label are included
     /// Example:
     /// - given: 'label', find: 'lazy'
-    Intersects(usize, &'a str),
+    Intersects(usize, &'a K, usize),
     /// The given label is not a match to an element
     /// The expected index for the given label is returned
     /// Example:
@@ -49,17 +93,119 @@ enum FindOutcome<'a> {
     BeyondSizeLimit,
 }
 
+/// Where a descent along a label stops, below the elements matched on the way
+enum Stop {
+    /// The label is empty, so nothing is matched
+    Empty,
+    /// The last matched element ends exactly where the label ends
+    Exact,
+    /// No child shares a prefix with the rest of the label.
+    /// The index the rest of the label would be inserted at is included
+    Missing(usize),
+    /// The rest of the label is a prefix of a child. The index of that child is included
+    Within(usize),
+    /// The rest of the label shares a prefix with a child. The index of that child is included,
+    /// along with the index of the rest of the label among the two children of the fork joining them
+    Forks(usize, usize),
+}
+
+impl<T, O, N, K> Default for RadixTrie<T, O, N, K>
+where
+    K: ?Sized + TrieKey,
+    O: KeyOrdering<K::Unit> + Default,
+    N: KeyNormalizer<K> + Default,
+{
+    fn default() -> Self {
+        Self::with_ordering_and_normalizer(O::default(), N::default())
+    }
+}
+
 impl<T> RadixTrie<T> {
-    /// Construct a new trie
+    /// Construct a new trie.
+    /// Tries with other key types are constructed with `RadixTrie::default`
     pub fn new() -> Self {
+        Self::with_ordering(Lexicographic)
+    }
+}
+
+impl<T> RadixTrie<T, CaseInsensitive> {
+    /// Construct a new trie whose labels are compared regardless of their cases.
+    /// The labels keep their original cases for enumeration
+    /// # Example
+    /// ```rust
+    /// use another_radix_trie::RadixTrie;
+    ///
+    /// let mut trie = RadixTrie::<usize, _>::case_insensitive();
+    /// trie.insert("Label", 5);
+    /// assert_eq!(trie.find("LABEL"), Some(&5));
+    /// assert_eq!(trie.start_with("la"), vec![(String::from("Label"), &5)]);
+    /// ```
+    pub fn case_insensitive() -> Self {
+        Self::with_ordering(CaseInsensitive)
+    }
+}
+
+impl<T, N, K: ?Sized + TrieKey> RadixTrie<T, Lexicographic, N, K> {
+    /// Construct a new trie whose labels are normalized with the given normalizer
+    /// # Example
+    /// ```rust
+    /// use another_radix_trie::{RadixTrie, Trim};
+    ///
+    /// let mut trie = RadixTrie::<usize, _, _>::with_normalizer(Trim);
+    /// trie.insert(" label ", 5);
+    /// assert_eq!(trie.find("label"), Some(&5));
+    /// ```
+    pub fn with_normalizer(normalizer: N) -> Self {
+        Self::with_ordering_and_normalizer(Lexicographic, normalizer)
+    }
+}
+
+impl<T, O, K: ?Sized + TrieKey> RadixTrie<T, O, Identity, K> {
+    /// Construct a new trie whose children are sorted with the given ordering
+    /// # Example
+    /// ```rust
+    /// use another_radix_trie::{Alphabetical, RadixTrie};
+    ///
+    /// let mut trie = RadixTrie::<(), _>::with_ordering(Alphabetical);
+    /// trie.insert("Zoo", ());
+    /// trie.insert("apple", ());
+    /// let labels = trie.iter().map(|(label, _)| label).collect::<Vec<_>>();
+    /// assert_eq!(labels, vec!["apple", "Zoo"]);
+    /// ```
+    pub fn with_ordering(ordering: O) -> Self {
+        Self::with_ordering_and_normalizer(ordering, Identity)
+    }
+}
+
+impl<T, O, N, K: ?Sized + TrieKey> RadixTrie<T, O, N, K> {
+    /// Construct a new trie whose children are sorted with the given ordering,
+    /// and whose labels are normalized with the given normalizer
+    /// # Example
+    /// ```rust
+    /// use another_radix_trie::{CaseInsensitive, Nfc, RadixTrie};
+    ///
+    /// let mut trie = RadixTrie::<usize, _, _>::with_ordering_and_normalizer(CaseInsensitive, Nfc);
+    /// trie.insert("CAF\u{45}\u{301}", 5);
+    /// assert_eq!(trie.find("caf\u{e9}"), Some(&5));
+    /// ```
+    pub fn with_ordering_and_normalizer(ordering: O, normalizer: N) -> Self {
         RadixTrie {
             entry: Element::Base {
-                label: "".to_owned(),
+                label: K::empty_owned(),
                 children: vec![],
             },
+            ordering,
+            normalizer,
         }
     }
+}
 
+impl<T, O, N, K> RadixTrie<T, O, N, K>
+where
+    K: ?Sized + TrieKey,
+    O: KeyOrdering<K::Unit>,
+    N: KeyNormalizer<K>,
+{
     /// Insert label and associated value into the trie.
     /// Values will be override if the label provided is already in the trie
     /// # Example
@@ -69,61 +215,143 @@ impl<T> RadixTrie<T> {
     /// let mut trie = RadixTrie::<()>::new();
     /// trie.insert("label", ());
     /// ```
-    pub fn insert(&mut self, mut label: &str, value: T) {
-        let mut entry = (&mut self.entry).children_mut();
-        while label.len() > 0 {
-            match Self::find_from_entry(&entry, label) {
-                BeyondSizeLimit => return entry.push(util::value_element(label, value, vec![])),
-                AsPrefixOf(index) => return Self::insert_prefix_node(entry, index, label, value),
-                Intersects(index, shared_prefix) => {
+    pub fn insert(&mut self, label: &K, value: T) {
+        let mut value = Some(value);
+        let found = self.find_or_insert_with(label, || value.take().unwrap());
+        if let Some((old_value, false)) = found {
+            *old_value = value.take().unwrap();
+        }
+    }
+
+    /// Returns the mutable borrowed value associated with related label,
+    /// with the value created by the function inserted if the label does not exist.
+    /// Whether the value is newly inserted is returned along with it.
+    /// If the label is empty, return None
+    pub(crate) fn find_or_insert_with<F: FnOnce() -> T>(
+        &mut self,
+        label: &K,
+        default: F,
+    ) -> Option<(&mut T, bool)> {
+        let normalized = self.normalizer.normalize(label);
+        // the label is only kept as inserted if the joined labels spell it differently
+        let spelling = |alike: bool| match alike {
+            true => None,
+            false => Some(ToOwned::to_owned(&*normalized)),
+        };
+        let mut alike = true;
+        let mut label: &K = &normalized;
+        let ordering = &self.ordering;
+        let mut entry = self.entry.children_mut();
+        while !label.is_empty() {
+            match Self::find_from_entry(ordering, entry, label) {
+                BeyondSizeLimit => {
+                    let inserted =
+                        util::value_element(label.to_owned(), spelling(alike), default(), vec![]);
+                    entry.push(inserted);
+                    return entry.last_mut()?.value_mut().map(|value| (value, true));
+                }
+                AsPrefixOf(index, prefix_len) => {
+                    alike &=
+                        util::spelled_alike(entry[index].label().split_at(prefix_len).0, label);
+                    let value = Self::insert_prefix_node(
+                        entry,
+                        index,
+                        prefix_len,
+                        spelling(alike),
+                        default(),
+                    );
+                    return Some((value, true));
+                }
+                Intersects(index, shared_prefix, shared_len) => {
+                    alike &= util::spelled_alike(shared_prefix, label.split_at(shared_len).0);
                     let shared_prefix = shared_prefix.to_owned();
-                    return Self::join_intersected_nodes(entry, index, shared_prefix, label, value);
+                    let value = Self::join_intersected_nodes(
+                        ordering,
+                        entry,
+                        index,
+                        shared_prefix,
+                        label.split_at(shared_len).1,
+                        spelling(alike),
+                        default(),
+                    );
+                    return Some((value, true));
                 }
                 NotMatch(index) => {
-                    let merged = util::value_element(label, value, vec![]);
-                    return entry.insert(index, merged);
+                    let merged =
+                        util::value_element(label.to_owned(), spelling(alike), default(), vec![]);
+                    entry.insert(index, merged);
+                    return entry[index].value_mut().map(|value| (value, true));
                 }
                 ExactMatch(index) => {
-                    let target = &mut entry[index];
-                    return match target.value_mut() {
-                        Some(old_value) => *old_value = value,
-                        None => Element::node_to_value(&mut entry[index], value),
-                    };
+                    let inserted = entry[index].is_node();
+                    if inserted {
+                        alike &= util::spelled_alike(entry[index].label(), label);
+                        Element::node_to_value(&mut entry[index], spelling(alike), default());
+                    }
+                    return entry[index].value_mut().map(|value| (value, inserted));
                 }
-                PrefixMatch(index) => {
-                    let target = &mut entry[index];
-                    label = &label[target.label().len()..];
-                    entry = target.children_mut();
+                PrefixMatch(index, matched_len) => {
+                    let (matched, rest) = label.split_at(matched_len);
+                    alike &= util::spelled_alike(entry[index].label(), matched);
+                    label = rest;
+                    entry = entry[index].children_mut();
                 }
             }
         }
+        None
     }
 
-    fn insert_prefix_node(entry: &mut Vec<Element<T>>, index: usize, label: &str, value: T) {
+    /// When the label is a prefix of an existing node, call this helper to process.
+    /// The new node keeps the label of the existing node
+    fn insert_prefix_node(
+        entry: &mut Vec<Element<T, K>>,
+        index: usize,
+        prefix_len: usize,
+        spelling: Option<K::Owned>,
+        value: T,
+    ) -> &mut T {
         let mut origin = entry.remove(index);
-        origin.remove_label_prefix(label.len());
-        let new_value = util::value_element(label, value, vec![origin]);
-        return entry.insert(index, new_value);
+        let label = origin.label().split_at(prefix_len).0.to_owned();
+        origin.remove_label_prefix(prefix_len);
+        let new_value = util::value_element(label, spelling, value, vec![origin]);
+        entry.insert(index, new_value);
+        entry[index].value_mut().expect("Inserted value element")
     }
 
-    /// When two nodes have intersected labels, call this helper to process
-    fn join_intersected_nodes(
-        entry: &mut Vec<Element<T>>,
+    /// When two nodes have intersected labels, call this helper to process.
+    /// The rest label is the part of the given label after the shared prefix
+    fn join_intersected_nodes<'a>(
+        ordering: &O,
+        entry: &'a mut Vec<Element<T, K>>,
         index: usize,
-        shared_prefix: String,
-        label: &str,
+        shared_prefix: K::Owned,
+        rest_label: &K,
+        spelling: Option<K::Owned>,
         value: T,
-    ) {
+    ) -> &'a mut T {
         let mut original = entry.remove(index);
-        original.remove_label_prefix(shared_prefix.len());
-        let new = util::value_element(&label[shared_prefix.len()..], value, vec![]);
-        let mut children = vec![original, new];
-        children.sort_by(|e1, e2| e1.label().cmp(e2.label()));
+        original.remove_label_prefix(shared_prefix.borrow().len());
+        let new = util::value_element(rest_label.to_owned(), spelling, value, vec![]);
+        let new_index = Self::fork_index(ordering, rest_label, original.label());
+        let mut children = vec![original];
+        children.insert(new_index, new);
         let merged = Element::Node {
             label: shared_prefix,
             children,
         };
-        entry.insert(index, merged)
+        entry.insert(index, merged);
+        entry[index].children_mut()[new_index]
+            .value_mut()
+            .expect("Inserted value element")
+    }
+
+    /// Returns the index of the new label among the two children of the node joining it
+    /// with an existing label, once their shared prefix is removed from both
+    fn fork_index(ordering: &O, rest_label: &K, original_label: &K) -> usize {
+        match rest_label.cmp_first_unit(original_label, ordering) {
+            Ordering::Less => 0,
+            _ => 1,
+        }
     }
 
     /// Returns the borrowed value associated with related label.
@@ -137,22 +365,125 @@ impl<T> RadixTrie<T> {
     /// assert_eq!(trie.find("label"), Some(&5));
     /// assert_eq!(trie.find("not exist"), None);
     /// ```
-    pub fn find(&self, mut label: &str) -> Option<&T> {
-        let mut entry = self.entry.children();
-        while label.len() > 0 {
-            match Self::find_from_entry(&entry, label) {
-                NotMatch(_) | AsPrefixOf(_) | Intersects(_, _) | BeyondSizeLimit => break,
-                PrefixMatch(target_index) => {
-                    let target = &entry[target_index];
-                    label = &label[target.label().len()..];
-                    entry = target.children();
+    pub fn find(&self, label: &K) -> Option<&T> {
+        let mut target = None;
+        match self.descend(&self.normalizer.normalize(label), |_, _, element| {
+            target = Some(element)
+        }) {
+            Stop::Exact => target.and_then(Element::value),
+            _ => None,
+        }
+    }
+
+    /// Returns the borrowed values associated with the labels, in the order of the labels.
+    /// The descent path is shared between consecutive labels, down to their longest shared prefix,
+    /// so sorted labels are found faster than one by one
+    /// # Example
+    /// ```rust
+    /// use another_radix_trie::RadixTrie;
+    ///
+    /// let mut trie = RadixTrie::<usize>::new();
+    /// trie.insert("lab", 3);
+    /// trie.insert("label", 5);
+    /// assert_eq!(trie.get_many(&["la", "lab", "label"]), vec![None, Some(&3), Some(&5)]);
+    /// ```
+    pub fn get_many(&self, labels: &[&K]) -> Vec<Option<&T>> {
+        let mut results = Vec::with_capacity(labels.len());
+        let mut previous: Option<Cow<K>> = None;
+        // the children visited along the descent path, with the length of the label consumed above them
+        let mut path = vec![(self.entry.children(), 0)];
+        for label in labels {
+            let label = self.normalizer.normalize(label);
+            // only an identical prefix is sure to be split at the same positions as the previous label
+            let shared_len = match &previous {
+                Some(previous) => previous.identical_prefix_len(&label),
+                None => 0,
+            };
+            while path.len() > 1 && path[path.len() - 1].1 >= shared_len {
+                path.pop();
+            }
+            let (mut entry, mut consumed) = path[path.len() - 1];
+            let found = loop {
+                let rest = label.split_at(consumed).1;
+                if rest.is_empty() {
+                    break None;
                 }
-                ExactMatch(target_index) => {
-                    return entry[target_index].value();
+                match Self::find_from_entry(&self.ordering, entry, rest) {
+                    NotMatch(_) | AsPrefixOf(..) | Intersects(..) | BeyondSizeLimit => break None,
+                    PrefixMatch(target_index, matched_len) => {
+                        entry = entry[target_index].children();
+                        consumed += matched_len;
+                        path.push((entry, consumed));
+                    }
+                    ExactMatch(target_index) => break entry[target_index].value(),
                 }
+            };
+            results.push(found);
+            previous = Some(label);
+        }
+        results
+    }
+
+    /// Returns true if the label exists in the trie
+    /// # Example
+    /// ```rust
+    /// use another_radix_trie::RadixTrie;
+    ///
+    /// let mut trie = RadixTrie::<usize>::new();
+    /// trie.insert("label", 5);
+    /// assert!(trie.contains_key("label"));
+    /// assert!(!trie.contains_key("lab"));
+    /// ```
+    pub fn contains_key(&self, label: &K) -> bool {
+        self.find(label).is_some()
+    }
+
+    /// Returns true if any label in the trie starts with the given prefix.
+    /// Unlike `start_with`, the labels under the prefix are not visited
+    /// # Example
+    /// ```rust
+    /// use another_radix_trie::RadixTrie;
+    ///
+    /// let mut trie = RadixTrie::<usize>::new();
+    /// trie.insert("label", 5);
+    /// assert!(trie.contains_prefix("lab"));
+    /// assert!(!trie.contains_prefix("lap"));
+    /// ```
+    pub fn contains_prefix(&self, prefix: &K) -> bool {
+        match self.descend(&self.normalizer.normalize(prefix), |_, _, _| {}) {
+            Stop::Empty => !self.entry.children().is_empty(),
+            // every element is either a value or has values below it
+            Stop::Exact | Stop::Within(_) => true,
+            Stop::Missing(_) | Stop::Forks(..) => false,
+        }
+    }
+
+    /// Returns the borrowed value associated with related label, along with the stored label.
+    /// The stored label may differ from the given one if the trie normalizes labels
+    /// or treats different units as equal
+    /// # Example
+    /// ```rust
+    /// use another_radix_trie::RadixTrie;
+    ///
+    /// let mut trie = RadixTrie::<usize, _>::case_insensitive();
+    /// trie.insert("Label", 5);
+    /// assert_eq!(trie.get_key_value("LABEL"), Some((String::from("Label"), &5)));
+    /// assert_eq!(trie.get_key_value("not exist"), None);
+    /// ```
+    pub fn get_key_value(&self, label: &K) -> Option<(K::Owned, &T)> {
+        let mut stored_label = K::empty_owned();
+        let mut target = None;
+        let stop = self.descend(&self.normalizer.normalize(label), |_, _, element| {
+            K::push_owned(&mut stored_label, element.label());
+            target = Some(element);
+        });
+        match (stop, target) {
+            (Stop::Exact, Some(target)) => {
+                let key = target.key(stored_label.borrow()).to_owned();
+                target.value().map(|value| (key, value))
             }
+            _ => None,
         }
-        None
     }
 
     /// Returns the mutable borrowed value associated with related label.
@@ -166,22 +497,19 @@ impl<T> RadixTrie<T> {
     /// assert_eq!(trie.find_mut("label"), Some(&mut 5));
     /// assert_eq!(trie.find("not exist"), None);
     /// ```
-    pub fn find_mut(&mut self, mut label: &str) -> Option<&mut T> {
-        let mut entry = self.entry.children_mut();
-        while label.len() > 0 {
-            match Self::find_from_entry(&entry, label) {
-                NotMatch(_) | AsPrefixOf(_) | Intersects(_, _) | BeyondSizeLimit => break,
-                PrefixMatch(target_index) => {
-                    let target = &mut entry[target_index];
-                    label = &label[target.label().len()..];
-                    entry = target.children_mut();
-                }
-                ExactMatch(target_index) => {
-                    return entry[target_index].value_mut();
-                }
-            }
+    pub fn find_mut(&mut self, label: &K) -> Option<&mut T> {
+        let mut path = vec![];
+        match self.descend(&self.normalizer.normalize(label), |index, _, _| {
+            path.push(index)
+        }) {
+            Stop::Exact => path
+                .iter()
+                .fold(&mut self.entry, |element, index| {
+                    &mut element.children_mut()[*index]
+                })
+                .value_mut(),
+            _ => None,
         }
-        None
     }
 
     /// Removes the value associated with related label.
@@ -195,15 +523,37 @@ impl<T> RadixTrie<T> {
     /// assert_eq!(trie.remove("label"), Some(5));
     /// assert_eq!(trie.remove("not exist"), None);
     /// ```
-    pub fn remove(&mut self, mut label: &str) -> Option<T> {
+    pub fn remove(&mut self, label: &K) -> Option<T> {
+        self.remove_entry(label).map(|(_, value)| value)
+    }
+
+    /// Removes the value associated with related label, and returns it along with the stored label.
+    /// The stored label may differ from the given one if the trie normalizes labels
+    /// or treats different units as equal.
+    /// If the provided label does not exist in the trie, return None
+    /// # Example
+    /// ```rust
+    /// use another_radix_trie::RadixTrie;
+    ///
+    /// let mut trie = RadixTrie::<usize, _>::case_insensitive();
+    /// trie.insert("Label", 5);
+    /// assert_eq!(trie.remove_entry("LABEL"), Some((String::from("Label"), 5)));
+    /// assert_eq!(trie.remove_entry("label"), None);
+    /// ```
+    pub fn remove_entry(&mut self, label: &K) -> Option<(K::Owned, T)> {
+        let normalized = self.normalizer.normalize(label);
+        let mut label: &K = &normalized;
+        let mut stored_label = K::empty_owned();
         let mut parent = &mut self.entry;
-        while label.len() > 0 {
-            match Self::find_from_entry(parent.children(), label) {
-                BeyondSizeLimit | NotMatch(_) | Intersects(_, _) | AsPrefixOf(_) => break,
+        while !label.is_empty() {
+            match Self::find_from_entry(&self.ordering, parent.children(), label) {
+                BeyondSizeLimit | NotMatch(_) | Intersects(..) | AsPrefixOf(..) => break,
                 ExactMatch(target_index) => {
                     let parent_is_node = parent.is_node();
-                    let (label, value, mut children) =
-                        parent.children_mut().remove(target_index).unpack();
+                    let target = parent.children_mut().remove(target_index);
+                    K::push_owned(&mut stored_label, target.label());
+                    let key = target.key(stored_label.borrow()).to_owned();
+                    let (label, value, mut children) = target.unpack();
                     if children.len() > 1 {
                         // target node has more than one children. Make target node a none value node
                         parent
@@ -212,7 +562,7 @@ impl<T> RadixTrie<T> {
                     } else if children.len() == 1 {
                         // Only one child. Make the child parent
                         let mut child = children.pop().unwrap();
-                        child.add_label_prefix(label);
+                        child.add_label_prefix(label.borrow());
                         parent.children_mut().insert(target_index, child);
                     }
                     // if parent has only one node child and parent is node. Merge them
@@ -221,19 +571,20 @@ impl<T> RadixTrie<T> {
                         another_child.add_label_prefix(parent.label());
                         *parent = another_child;
                     }
-                    return value;
+                    return value.map(|value| (key, value));
                 }
-                PrefixMatch(target_index) => {
-                    let target = &parent.children()[target_index];
-                    label = &label[target.label().len()..];
+                PrefixMatch(target_index, matched_len) => {
+                    label = label.split_at(matched_len).1;
                     parent = &mut parent.children_mut()[target_index];
+                    K::push_owned(&mut stored_label, parent.label());
                 }
             }
         }
         None
     }
 
-    /// Returns all values with their labels where the labels start with given prefix
+    /// Returns all values with their labels where the labels start with given prefix.
+    /// An empty prefix matches nothing, unlike `prefix_iter` which visits every label
     /// # Example
     /// ```rust
     /// use another_radix_trie::RadixTrie;
@@ -243,65 +594,244 @@ impl<T> RadixTrie<T> {
     /// trie.insert("label", 5);
     /// assert_eq!(trie.start_with("la"), vec![(String::from("lab"), &3), (String::from("label"), &5)])
     /// ```
-    pub fn start_with(&self, mut prefix: &str) -> Vec<(String, &T)> {
+    pub fn start_with(&self, prefix: &K) -> Vec<(K::Owned, &T)> {
+        let prefix = self.normalizer.normalize(prefix);
+        if prefix.is_empty() {
+            return vec![];
+        }
+        match self.find_prefix_element(&prefix) {
+            Some((existing_prefix, element)) => {
+                element.collect_all_child_values(existing_prefix.borrow())
+            }
+            None => vec![],
+        }
+    }
+
+    /// Returns an iterator over all values with their labels, in sorted order
+    /// # Example
+    /// ```rust
+    /// use another_radix_trie::RadixTrie;
+    ///
+    /// let mut trie = RadixTrie::<usize>::new();
+    /// trie.insert("label", 5);
+    /// trie.insert("lab", 3);
+    /// assert_eq!(trie.iter().collect::<Vec<_>>(), vec![(String::from("lab"), &3), (String::from("label"), &5)])
+    /// ```
+    pub fn iter(&self) -> Iter<'_, T, K> {
+        Iter::new(K::empty_owned(), self.entry.children())
+    }
+
+    /// Returns an iterator over all values with their labels where the labels start with given prefix.
+    /// Unlike `start_with`, the labels are visited lazily in sorted order,
+    /// and an empty prefix visits every label like `iter`
+    /// # Example
+    /// ```rust
+    /// use another_radix_trie::RadixTrie;
+    ///
+    /// let mut trie = RadixTrie::<usize>::new();
+    /// trie.insert("lab", 3);
+    /// trie.insert("label", 5);
+    /// trie.insert("fox", 3);
+    /// let latest = trie.prefix_iter("la").rev().next();
+    /// assert_eq!(latest, Some((String::from("label"), &5)));
+    /// assert_eq!(trie.prefix_iter("").count(), 3);
+    /// assert_eq!(trie.start_with(""), vec![]);
+    /// ```
+    pub fn prefix_iter(&self, prefix: &K) -> Iter<'_, T, K> {
+        let prefix = self.normalizer.normalize(prefix);
+        match self.find_prefix_element(&prefix) {
+            Some((existing_prefix, element)) => {
+                Iter::new(existing_prefix, std::slice::from_ref(element))
+            }
+            None => Iter::empty(),
+        }
+    }
+
+    /// Returns the ordering of the trie
+    pub(crate) fn ordering(&self) -> &O {
+        &self.ordering
+    }
+
+    /// Returns the base element of the trie
+    pub(crate) fn root(&self) -> &Element<T, K> {
+        &self.entry
+    }
+
+    /// Normalizes the label with the normalizer of the trie
+    pub(crate) fn normalize<'a>(&self, label: &'a K) -> Cow<'a, K> {
+        self.normalizer.normalize(label)
+    }
+
+    /// Returns the indices of the elements among their siblings along the descent path
+    /// of the label once inserted, with how the insertion changes the structure of the trie.
+    /// The trie is not changed. If the label is empty, return None
+    pub(crate) fn insertion_path(&self, label: &K) -> Option<(Vec<usize>, Growth)> {
+        let mut path = vec![];
+        let growth = match self.descend(&self.normalizer.normalize(label), |index, _, _| {
+            path.push(index)
+        }) {
+            Stop::Empty => return None,
+            Stop::Exact => Growth::Existing,
+            Stop::Missing(index) => {
+                path.push(index);
+                Growth::Leaf
+            }
+            Stop::Within(index) => {
+                path.push(index);
+                Growth::Prefix
+            }
+            Stop::Forks(index, fork_index) => {
+                path.push(index);
+                path.push(fork_index);
+                Growth::Fork
+            }
+        };
+        Some((path, growth))
+    }
+
+    /// Calls `f` on the values whose labels are prefixes of the given label, from the shortest to the longest,
+    /// along with the lengths of the prefixes in the given label.
+    /// The label is not normalized, so the lengths are measured in the label as it is
+    pub(crate) fn prefix_matches<'a, F>(&'a self, label: &K, mut f: F)
+    where
+        F: FnMut(usize, &'a T),
+    {
+        self.descend(label, |_, matched, element| {
+            if let Some(value) = element.value() {
+                f(matched, value);
+            }
+        });
+    }
+
+    /// Same as the last of `prefix_matches`
+    pub(crate) fn longest_prefix_match(&self, label: &K) -> Option<(usize, &T)> {
+        let mut longest = None;
+        self.prefix_matches(label, |matched, value| longest = Some((matched, value)));
+        longest
+    }
+
+    /// Finds the element whose label starts with the given prefix after joined with its ancestors.
+    /// Returns the joined label of the ancestors along with the element.
+    /// The base element is returned for an empty prefix
+    pub(crate) fn find_prefix_element(&self, prefix: &K) -> Option<(K::Owned, &Element<T, K>)> {
+        let (existing_prefix, path) = self.find_prefix_path(prefix)?;
+        let element = path
+            .iter()
+            .fold(&self.entry, |element, index| &element.children()[*index]);
+        Some((existing_prefix, element))
+    }
+
+    /// Same as `find_prefix_element`, but returns the indices of the elements
+    /// among their siblings along the descent path instead of the element
+    pub(crate) fn find_prefix_path(&self, prefix: &K) -> Option<(K::Owned, Vec<usize>)> {
+        let mut path = vec![];
+        let mut ancestors = vec![];
+        let stop = self.descend(prefix, |index, _, element| {
+            path.push(index);
+            ancestors.push(element);
+        });
+        match stop {
+            // the exactly matched element is returned, so its label is not joined
+            Stop::Exact => {
+                ancestors.pop();
+            }
+            Stop::Within(index) => path.push(index),
+            Stop::Empty => {}
+            Stop::Missing(_) | Stop::Forks(..) => return None,
+        }
+        let mut existing_prefix = K::empty_owned();
+        for ancestor in ancestors {
+            K::push_owned(&mut existing_prefix, ancestor.label());
+        }
+        Some((existing_prefix, path))
+    }
+
+    /// Descends along the label, calling `visit` on each element whose label is matched in full,
+    /// with its index among its siblings and the length of the label matched down to it.
+    /// The label is not normalized
+    fn descend<'a, F>(&'a self, mut label: &K, mut visit: F) -> Stop
+    where
+        F: FnMut(usize, usize, &'a Element<T, K>),
+    {
+        if label.is_empty() {
+            return Stop::Empty;
+        }
+        let mut matched = 0;
         let mut entry = self.entry.children();
-        let mut prefixes: Vec<&str> = vec![];
-        while prefix.len() > 0 {
-            match Self::find_from_entry(entry, prefix) {
-                BeyondSizeLimit | NotMatch(_) | Intersects(_, _) => break,
-                PrefixMatch(target_index) => {
-                    // existing_label matches the prefix of label. Move to next node
-                    let target = &entry[target_index];
-                    prefixes.push(target.label());
-                    prefix = &prefix[target.label().len()..];
-                    entry = target.children();
+        loop {
+            match Self::find_from_entry(&self.ordering, entry, label) {
+                BeyondSizeLimit => return Stop::Missing(entry.len()),
+                NotMatch(index) => return Stop::Missing(index),
+                AsPrefixOf(index, _) => return Stop::Within(index),
+                Intersects(index, target_shared_prefix, shared_len) => {
+                    let original_label = entry[index].label().split_at(target_shared_prefix.len());
+                    let rest_label = label.split_at(shared_len).1;
+                    let fork_index = Self::fork_index(&self.ordering, rest_label, original_label.1);
+                    return Stop::Forks(index, fork_index);
+                }
+                ExactMatch(index) => {
+                    visit(index, matched + label.len(), &entry[index]);
+                    return Stop::Exact;
                 }
-                ExactMatch(target_index) | AsPrefixOf(target_index) => {
-                    let existing_prefix: String = prefixes.join("");
-                    return Self::format_children(&entry[target_index], &existing_prefix);
+                PrefixMatch(index, matched_len) => {
+                    matched += matched_len;
+                    visit(index, matched, &entry[index]);
+                    label = label.split_at(matched_len).1;
+                    entry = entry[index].children();
                 }
             }
         }
-        vec![]
-    }
-
-    fn format_children<'a>(entry: &'a Element<T>, prefix: &str) -> Vec<(String, &'a T)> {
-        entry
-            .collect_all_child_values()
-            .into_iter()
-            .map(|(mut label, value)| {
-                label.insert_str(0, prefix);
-                (label, value)
-            })
-            .collect()
     }
 
     /// Run a binary search on the given entry and return outcome based on different conditions
-    fn find_from_entry<'a>(entry: &'a [Element<T>], label: &'a str) -> FindOutcome<'a> {
-        let char = util::first_char(label);
-        let target_index = util::binary_search(char, entry);
+    fn find_from_entry<'a>(
+        ordering: &O,
+        entry: &'a [Element<T, K>],
+        label: &'a K,
+    ) -> FindOutcome<'a, K> {
+        let target_index = util::binary_search(label, entry, ordering);
         if target_index >= entry.len() {
             return BeyondSizeLimit;
         }
         let target = entry[target_index].label();
-        let shared_prefix = util::longest_shared_prefix(label, target);
+        let (shared_prefix, target_shared_prefix) =
+            util::longest_shared_prefix(label, target, ordering);
+        let covers_label = shared_prefix.len() == label.len();
+        let covers_target = target_shared_prefix.len() == target.len();
         if shared_prefix.is_empty() {
             NotMatch(target_index)
-        } else if target == label {
+        } else if covers_label && covers_target {
             ExactMatch(target_index)
-        } else if shared_prefix == target {
-            PrefixMatch(target_index)
-        } else if shared_prefix == label {
-            AsPrefixOf(target_index)
+        } else if covers_target {
+            PrefixMatch(target_index, shared_prefix.len())
+        } else if covers_label {
+            AsPrefixOf(target_index, target_shared_prefix.len())
         } else {
-            Intersects(target_index, shared_prefix)
+            Intersects(target_index, target_shared_prefix, shared_prefix.len())
         }
     }
 }
 
+impl<'a, T, O, N, K> IntoIterator for &'a RadixTrie<T, O, N, K>
+where
+    K: ?Sized + TrieKey,
+    O: KeyOrdering<K::Unit>,
+    N: KeyNormalizer<K>,
+{
+    type Item = (K::Owned, &'a T);
+    type IntoIter = Iter<'a, T, K>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
 #[cfg(test)]
 mod trie_tests {
-    use crate::trie::RadixTrie;
+    use crate::element::Element;
+    use crate::normalizer::{Identity, Nfc, Trim};
+    use crate::ordering::{Alphabetical, CaseInsensitive, Lexicographic};
+    use crate::trie::{ByteRadixTrie, RadixTrie};
 
     #[test]
     fn test_insert_find_remove() {
@@ -395,4 +925,306 @@ mod trie_tests {
         let found = trie.find("ON");
         assert_eq!(found, Some(&416));
     }
+
+    #[test]
+    fn test_with_ordering() {
+        let mut trie = RadixTrie::<usize, _>::with_ordering(Alphabetical);
+        let words = ["banana", "Zebra", "apple", "Apple", "zoo", "Banana"];
+        for word in &words {
+            trie.insert(word, word.len())
+        }
+        let res = trie.iter().map(|(label, _)| label).collect::<Vec<_>>();
+        let expected = vec!["Apple", "apple", "Banana", "banana", "Zebra", "zoo"];
+        assert_eq!(res, expected);
+        for word in &words {
+            assert_eq!(trie.find(word), Some(&word.len()));
+        }
+        assert_eq!(trie.remove("Banana"), Some(6));
+        assert_eq!(trie.find("banana"), Some(&6));
+    }
+
+    #[test]
+    fn test_with_closure_ordering() {
+        let mut trie = RadixTrie::<(), _>::with_ordering(|c1: &char, c2: &char| c2.cmp(c1));
+        trie.insert("ab", ());
+        trie.insert("ac", ());
+        trie.insert("b", ());
+        let res = trie.iter().map(|(label, _)| label).collect::<Vec<_>>();
+        assert_eq!(res, vec!["b", "ac", "ab"]);
+    }
+
+    fn count_spellings(element: &Element<usize>) -> usize {
+        let kept = matches!(
+            element,
+            Element::Value {
+                spelling: Some(_),
+                ..
+            }
+        );
+        let children = element
+            .children()
+            .iter()
+            .map(count_spellings)
+            .sum::<usize>();
+        children + kept as usize
+    }
+
+    #[test]
+    fn test_spelling_kept_only_if_different() {
+        let words = ["Won", "wonder", "WONDERFUL", "World", "axes"];
+        let mut trie = RadixTrie::<usize>::new();
+        let mut case_insensitive = RadixTrie::<usize, _>::case_insensitive();
+        for word in &words {
+            trie.insert(word, word.len());
+            case_insensitive.insert(word, word.len());
+        }
+        assert_eq!(count_spellings(trie.root()), 0);
+        // only "wonder" and "WONDERFUL" are under the label "Won"
+        assert_eq!(count_spellings(case_insensitive.root()), 2);
+        case_insensitive.remove("Won");
+        assert_eq!(count_spellings(case_insensitive.root()), 2);
+        assert_eq!(
+            case_insensitive.get_key_value("wonderful"),
+            Some(("WONDERFUL".into(), &9))
+        );
+    }
+
+    #[test]
+    fn test_case_insensitive() {
+        let mut trie = RadixTrie::<usize, _>::with_ordering(CaseInsensitive);
+        let words = ["Won", "wonder", "WONDERFUL", "World", "axes"];
+        for word in &words {
+            trie.insert(word, word.len())
+        }
+        assert_eq!(trie.find("won"), Some(&3));
+        assert_eq!(trie.find("Wonderful"), Some(&9));
+        assert_eq!(trie.find("AXES"), Some(&4));
+        let res = trie.iter().map(|(label, _)| label).collect::<Vec<_>>();
+        assert_eq!(res, vec!["axes", "Won", "wonder", "WONDERFUL", "World"]);
+        trie.insert("WON", 0);
+        assert_eq!(trie.find("won"), Some(&0));
+        assert_eq!(trie.remove("wOn"), Some(0));
+        assert_eq!(trie.find("won"), None);
+        assert_eq!(trie.find("WONDER"), Some(&6));
+    }
+
+    #[test]
+    fn test_case_insensitive_different_char_lengths() {
+        // the Kelvin sign is lowercased to 'k', but takes 3 bytes
+        let mut trie = RadixTrie::<usize, _>::case_insensitive();
+        trie.insert("\u{212A}elvin", 1);
+        trie.insert("keep", 2);
+        trie.insert("k", 3);
+        assert_eq!(trie.find("KELVIN"), Some(&1));
+        assert_eq!(trie.find("KEEP"), Some(&2));
+        assert_eq!(trie.find("\u{212A}"), Some(&3));
+        assert_eq!(trie.start_with("ke").len(), 2);
+        assert_eq!(trie.remove("kelvin"), Some(1));
+        assert_eq!(trie.find("keep"), Some(&2));
+    }
+
+    #[test]
+    fn test_get_many() {
+        let mut trie = RadixTrie::<usize, _>::case_insensitive();
+        let words = ["exe", "execute", "exec", "example", "\u{212A}elvin", "keep"];
+        for word in &words {
+            trie.insert(word, word.len())
+        }
+        let mut labels = vec![
+            "", "e", "ex", "exa", "example", "EXE", "exec", "execs", "execute", "executed", "exe",
+            "kelvin", "KEEP", "k", "z",
+        ];
+        let expected = labels
+            .iter()
+            .map(|label| trie.find(label))
+            .collect::<Vec<_>>();
+        assert_eq!(trie.get_many(&labels), expected);
+        labels.sort();
+        let expected = labels
+            .iter()
+            .map(|label| trie.find(label))
+            .collect::<Vec<_>>();
+        assert_eq!(trie.get_many(&labels), expected);
+        assert_eq!(trie.get_many(&[]), vec![]);
+    }
+
+    #[test]
+    fn test_get_many_different_char_lengths() {
+        // the labels are equal regardless of cases, but split at different positions
+        let mut trie = RadixTrie::<usize, _>::case_insensitive();
+        trie.insert("\u{212A}a", 1);
+        trie.insert("\u{212A}b", 2);
+        assert_eq!(trie.get_many(&["\u{212A}k", "k\u{212A}"]), vec![None, None]);
+        assert_eq!(
+            trie.get_many(&["\u{212A}A", "kB"]),
+            vec![Some(&1), Some(&2)]
+        );
+    }
+
+    #[test]
+    fn test_longest_prefix_match() {
+        let mut trie = RadixTrie::<usize>::new();
+        for (index, word) in ["a", "ab", "abcd", "b"].iter().enumerate() {
+            trie.insert(word, index);
+        }
+        let mut matches = vec![];
+        trie.prefix_matches("abcde", |len, value| matches.push((len, *value)));
+        assert_eq!(matches, vec![(1, 0), (2, 1), (4, 2)]);
+        assert_eq!(trie.longest_prefix_match("abcde"), Some((4, &2)));
+        assert_eq!(trie.longest_prefix_match("abd"), Some((2, &1)));
+        assert_eq!(trie.longest_prefix_match("b"), Some((1, &3)));
+        assert_eq!(trie.longest_prefix_match("c"), None);
+        assert_eq!(trie.longest_prefix_match(""), None);
+    }
+
+    #[test]
+    fn test_contains() {
+        let mut trie = RadixTrie::<usize>::new();
+        assert!(!trie.contains_prefix(""));
+        trie.insert("exe", 3);
+        trie.insert("execute", 7);
+        trie.insert("example", 7);
+        assert!(trie.contains_key("exe"));
+        assert!(!trie.contains_key("ex"));
+        assert!(!trie.contains_key("exec"));
+        for prefix in &["", "e", "ex", "exa", "exe", "exec", "execute"] {
+            assert!(trie.contains_prefix(prefix), "{}", prefix);
+        }
+        for prefix in &["a", "exx", "execs", "executed"] {
+            assert!(!trie.contains_prefix(prefix), "{}", prefix);
+        }
+    }
+
+    #[test]
+    fn test_get_key_value_remove_entry() {
+        let mut trie = RadixTrie::<usize, _>::case_insensitive();
+        trie.insert("Won", 3);
+        trie.insert("wonder", 6);
+        trie.insert("WONDERFUL", 9);
+        assert_eq!(trie.get_key_value("WONDER"), Some(("wonder".into(), &6)));
+        assert_eq!(
+            trie.get_key_value("wonderful"),
+            Some(("WONDERFUL".into(), &9))
+        );
+        assert_eq!(trie.get_key_value("wo"), None);
+        assert_eq!(trie.remove_entry("WONDER"), Some(("wonder".into(), 6)));
+        assert_eq!(trie.remove_entry("WONDER"), None);
+        assert_eq!(
+            trie.remove_entry("wonderFul"),
+            Some(("WONDERFUL".into(), 9))
+        );
+        let mut trie = RadixTrie::<usize, _, _>::with_normalizer(Trim);
+        trie.insert(" label ", 5);
+        assert_eq!(trie.get_key_value("label\t"), Some(("label".into(), &5)));
+    }
+
+    #[test]
+    fn test_with_normalizer() {
+        let mut trie = RadixTrie::<usize, _, _>::with_normalizer((Trim, Nfc));
+        // "é" in a composed form, and a decomposed form
+        trie.insert("caf\u{e9}", 1);
+        trie.insert(" cafe\u{301}s\t", 2);
+        assert_eq!(trie.find("cafe\u{301}"), Some(&1));
+        assert_eq!(trie.find("caf\u{e9}s"), Some(&2));
+        assert_eq!(trie.start_with("cafe\u{301} ").len(), 2);
+        *trie.find_mut("  caf\u{e9}").unwrap() = 3;
+        assert_eq!(trie.remove("cafe\u{301}"), Some(3));
+        assert_eq!(trie.iter().next(), Some((String::from("caf\u{e9}s"), &2)));
+    }
+
+    #[test]
+    fn test_new_infers_str_keys() {
+        let mut trie = RadixTrie::new();
+        let words = ["Won", "Wonder"];
+        for word in &words {
+            trie.insert(word, word.len())
+        }
+        assert_eq!(trie.find("Won"), Some(&3));
+    }
+
+    #[test]
+    fn test_u16_keys() {
+        let mut trie = RadixTrie::<usize, Lexicographic, Identity, [u16]>::default();
+        let words: Vec<Vec<u16>> = ["Won", "Wonder", "Wonderful", "World", "Axes"]
+            .iter()
+            .map(|word| word.encode_utf16().collect())
+            .collect();
+        for word in &words {
+            trie.insert(word, word.len())
+        }
+        let prefix = "Won".encode_utf16().collect::<Vec<_>>();
+        let res = trie
+            .start_with(&prefix)
+            .into_iter()
+            .map(|(label, _)| String::from_utf16(&label).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(res, vec!["Won", "Wonder", "Wonderful"]);
+        for word in &words {
+            assert_eq!(trie.find(word), Some(&word.len()));
+            assert_eq!(trie.remove(word), Some(word.len()));
+        }
+        assert_eq!(trie.iter().next(), None);
+    }
+
+    #[test]
+    fn test_token_keys() {
+        let mut trie = RadixTrie::<usize, Lexicographic, Identity, [&str]>::default();
+        trie.insert(&["the", "quick", "fox"], 1);
+        trie.insert(&["the", "quick", "dog"], 2);
+        trie.insert(&["the", "lazy", "dog"], 3);
+        trie.insert(&["the"], 4);
+        assert_eq!(trie.find(&["the", "quick", "dog"]), Some(&2));
+        assert_eq!(trie.find(&["the", "quick"]), None);
+        let res = trie.prefix_iter(&["the", "quick"]).collect::<Vec<_>>();
+        let expected: Vec<(Vec<&str>, &usize)> = vec![
+            (vec!["the", "quick", "dog"], &2),
+            (vec!["the", "quick", "fox"], &1),
+        ];
+        assert_eq!(res, expected);
+        assert_eq!(trie.remove(&["the"]), Some(4));
+        assert_eq!(trie.iter().count(), 3);
+    }
+
+    #[test]
+    fn test_byte_keys() {
+        let mut trie = ByteRadixTrie::<usize>::default();
+        // "é" in UTF-8 is [0xc3, 0xa9], so the keys split in the middle of it
+        let words: [&[u8]; 5] = [
+            b"caf\xc3\xa9",
+            b"caf\xc3\xa8",
+            b"caf\xc3",
+            b"\xff\xfe",
+            b"caf",
+        ];
+        for word in &words {
+            trie.insert(word, word.len())
+        }
+        let res = trie
+            .start_with(b"caf\xc3")
+            .into_iter()
+            .map(|(label, _)| label)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            res,
+            vec![
+                b"caf\xc3".to_vec(),
+                b"caf\xc3\xa8".to_vec(),
+                b"caf\xc3\xa9".to_vec()
+            ]
+        );
+        assert_eq!(trie.iter().next_back(), Some((b"\xff\xfe".to_vec(), &2)));
+        for word in &words {
+            assert_eq!(trie.find(word), Some(&word.len()));
+            assert_eq!(trie.remove(word), Some(word.len()));
+        }
+        assert_eq!(trie.iter().next(), None);
+    }
+
+    #[test]
+    fn test_byte_keys_case_insensitive() {
+        let mut trie = ByteRadixTrie::<usize, _>::with_ordering(CaseInsensitive);
+        trie.insert(b"Label\xff", 1);
+        assert_eq!(trie.find(b"LABEL\xff"), Some(&1));
+        assert_eq!(trie.find(b"label\xfe"), None);
+    }
 }
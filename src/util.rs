@@ -1,12 +1,19 @@
 use crate::element::Element;
+use crate::key::TrieKey;
+use crate::ordering::KeyOrdering;
+use std::cmp::Ordering;
 
-pub fn binary_search<T>(target: char, array: &[Element<T>]) -> usize {
+pub fn binary_search<T, K, O>(target: &K, array: &[Element<T, K>], ordering: &O) -> usize
+where
+    K: ?Sized + TrieKey,
+    O: KeyOrdering<K::Unit>,
+{
     let mut first = 0;
     let mut last = array.len();
     while first < last {
         let mid = first + (last - first) / 2;
         let mid_val = array[mid].label();
-        if mid_val.chars().next().unwrap() < target {
+        if mid_val.cmp_first_unit(target, ordering) == Ordering::Less {
             first = mid + 1;
         } else {
             last = mid;
@@ -15,40 +22,146 @@ pub fn binary_search<T>(target: char, array: &[Element<T>]) -> usize {
     first
 }
 
-pub fn longest_shared_prefix<'a>(s1: &'a str, s2: &'a str) -> &'a str {
-    for ((index1, char1), char2) in s1.char_indices().zip(s2.chars()) {
-        if char1 != char2 {
-            return &s1[..index1];
-        }
+/// Returns the longest shared prefix of the two keys, as spelled in each of them.
+/// The spellings differ only if the ordering treats different units as equal
+pub fn longest_shared_prefix<'a, 'b, K, O>(s1: &'a K, s2: &'b K, ordering: &O) -> (&'a K, &'b K)
+where
+    K: ?Sized + TrieKey,
+    O: KeyOrdering<K::Unit>,
+{
+    let (len1, len2) = s1.shared_prefix_len(s2, ordering);
+    (s1.split_at(len1).0, s2.split_at(len2).0)
+}
+
+/// Compares the two keys unit by unit, a key is less than the keys it is a prefix of
+pub fn compare_keys<K, O>(k1: &K, k2: &K, ordering: &O) -> Ordering
+where
+    K: ?Sized + TrieKey,
+    O: KeyOrdering<K::Unit>,
+{
+    let (len1, len2) = k1.shared_prefix_len(k2, ordering);
+    let (rest1, rest2) = (k1.split_at(len1).1, k2.split_at(len2).1);
+    match (rest1.is_empty(), rest2.is_empty()) {
+        (true, true) => Ordering::Equal,
+        (true, false) => Ordering::Less,
+        (false, true) => Ordering::Greater,
+        (false, false) => rest1.cmp_first_unit(rest2, ordering),
     }
-    return if s1.len() > s2.len() { s2 } else { s1 };
+}
+
+/// Returns true if the two keys have the same units, regardless of any ordering
+pub fn spelled_alike<K: ?Sized + TrieKey>(k1: &K, k2: &K) -> bool {
+    k1.len() == k2.len() && k1.identical_prefix_len(k2) == k1.len()
 }
 
 /// A helper function to create an value element
-pub fn value_element<T, S: ToString>(label: S, value: T, children: Vec<Element<T>>) -> Element<T> {
+pub fn value_element<T, K: ?Sized + TrieKey>(
+    label: K::Owned,
+    spelling: Option<K::Owned>,
+    value: T,
+    children: Vec<Element<T, K>>,
+) -> Element<T, K> {
     Element::Value {
-        label: label.to_string(),
+        label,
+        spelling,
         value,
         children,
     }
 }
 
-pub fn first_char<S: AsRef<str>>(s: S) -> char {
-    s.as_ref()
-        .chars()
-        .next()
-        .expect("First char called on empty string")
+/// Values stored by the ids handed out when they are inserted,
+/// for the structures referring to a value from more than one place.
+/// The ids of the removed values are handed out again
+pub struct Slab<T> {
+    entries: Vec<Option<T>>,
+    vacant: Vec<usize>,
+}
+
+impl<T> Slab<T> {
+    pub fn new() -> Self {
+        Slab {
+            entries: vec![],
+            vacant: vec![],
+        }
+    }
+
+    /// Stores the value and returns its id
+    pub fn insert(&mut self, value: T) -> usize {
+        match self.vacant.pop() {
+            Some(id) => {
+                self.entries[id] = Some(value);
+                id
+            }
+            None => {
+                self.entries.push(Some(value));
+                self.entries.len() - 1
+            }
+        }
+    }
+
+    pub fn get(&self, id: usize) -> Option<&T> {
+        self.entries.get(id)?.as_ref()
+    }
+
+    pub fn get_mut(&mut self, id: usize) -> Option<&mut T> {
+        self.entries.get_mut(id)?.as_mut()
+    }
+
+    pub fn remove(&mut self, id: usize) -> Option<T> {
+        let value = self.entries.get_mut(id)?.take()?;
+        self.vacant.push(id);
+        Some(value)
+    }
 }
 
 #[cfg(test)]
 mod util_tests {
+    use crate::ordering::{CaseInsensitive, Lexicographic};
     use crate::util;
 
     #[test]
     fn longest_shared_prefix_non_alphabetic_test() {
         let s1 = "Toronto多倫多";
         let s2 = "Toronto多伦多";
-        let prefix = util::longest_shared_prefix(s1, s2);
+        let (prefix, _) = util::longest_shared_prefix(s1, s2, &Lexicographic);
         assert_eq!(prefix, "Toronto多");
     }
+
+    #[test]
+    fn compare_keys_test() {
+        use std::cmp::Ordering;
+        assert_eq!(
+            util::compare_keys("lab", "label", &Lexicographic),
+            Ordering::Less
+        );
+        assert_eq!(
+            util::compare_keys("lazy", "label", &Lexicographic),
+            Ordering::Greater
+        );
+        assert_eq!(
+            util::compare_keys("LABEL", "label", &CaseInsensitive),
+            Ordering::Equal
+        );
+    }
+
+    #[test]
+    fn longest_shared_prefix_case_insensitive_test() {
+        let s1 = "KELVIN";
+        let s2 = "\u{212A}elvin scale";
+        let prefix = util::longest_shared_prefix(s1, s2, &CaseInsensitive);
+        assert_eq!(prefix, ("KELVIN", "\u{212A}elvin"));
+    }
+
+    #[test]
+    fn slab_reuses_ids_test() {
+        let mut slab = util::Slab::new();
+        assert_eq!(slab.insert("a"), 0);
+        assert_eq!(slab.insert("b"), 1);
+        assert_eq!(slab.remove(0), Some("a"));
+        assert_eq!(slab.remove(0), None);
+        assert_eq!(slab.get(0), None);
+        assert_eq!(slab.insert("c"), 0);
+        assert_eq!(slab.get(0), Some(&"c"));
+        assert_eq!(slab.get(2), None);
+    }
 }
@@ -0,0 +1,376 @@
+use crate::element::Element;
+use crate::key::TrieKey;
+use crate::normalizer::{Identity, KeyNormalizer};
+use crate::ordering::{KeyOrdering, Lexicographic};
+use crate::rank::{self, Bound};
+use crate::trie::{Growth, RadixTrie};
+
+/// WeightedRadixTrie stores values with weights, like query suggestions with their popularity.
+/// The highest weight under every element is cached in a tree mirroring the elements,
+/// and kept up to date on changes, so the completions with the highest weights are found
+/// without visiting the subtrees whose highest weights cannot make the top k
+///
+/// # Example
+/// ```rust
+/// use another_radix_trie::WeightedRadixTrie;
+///
+/// let mut trie = WeightedRadixTrie::<()>::new();
+/// trie.insert("wonder", (), 10);
+/// trie.insert("wonderful", (), 30);
+/// trie.insert("won", (), 20);
+/// trie.insert("world", (), 40);
+/// let res = trie.top_completions("won", 2);
+/// let expected = vec![
+///     (String::from("wonderful"), &(), 30),
+///     (String::from("won"), &(), 20),
+/// ];
+/// assert_eq!(res, expected);
+/// ```
+pub struct WeightedRadixTrie<T, W = u64, O = Lexicographic, N = Identity, K: ?Sized + TrieKey = str>
+{
+    trie: RadixTrie<(W, T), O, N, K>,
+    /// The highest weight under each element, mirroring the elements from the base one
+    bounds: Bound<W>,
+}
+
+impl<T, W, O, N, K> Default for WeightedRadixTrie<T, W, O, N, K>
+where
+    K: ?Sized + TrieKey,
+    O: KeyOrdering<K::Unit> + Default,
+    N: KeyNormalizer<K> + Default,
+{
+    fn default() -> Self {
+        Self::with_ordering_and_normalizer(O::default(), N::default())
+    }
+}
+
+impl<T, W> WeightedRadixTrie<T, W> {
+    /// Construct a new trie.
+    /// Tries with other key types are constructed with `WeightedRadixTrie::default`
+    pub fn new() -> Self {
+        Self::with_ordering_and_normalizer(Lexicographic, Identity)
+    }
+}
+
+impl<T, W, O, K: ?Sized + TrieKey> WeightedRadixTrie<T, W, O, Identity, K> {
+    /// Construct a new trie whose labels are sorted with the given ordering
+    pub fn with_ordering(ordering: O) -> Self {
+        Self::with_ordering_and_normalizer(ordering, Identity)
+    }
+}
+
+impl<T, W, N, K: ?Sized + TrieKey> WeightedRadixTrie<T, W, Lexicographic, N, K> {
+    /// Construct a new trie whose labels are normalized with the given normalizer
+    pub fn with_normalizer(normalizer: N) -> Self {
+        Self::with_ordering_and_normalizer(Lexicographic, normalizer)
+    }
+}
+
+impl<T, W, O, N, K: ?Sized + TrieKey> WeightedRadixTrie<T, W, O, N, K> {
+    /// Construct a new trie whose labels are sorted with the given ordering,
+    /// and normalized with the given normalizer
+    pub fn with_ordering_and_normalizer(ordering: O, normalizer: N) -> Self {
+        WeightedRadixTrie {
+            trie: RadixTrie::with_ordering_and_normalizer(ordering, normalizer),
+            bounds: Bound {
+                max: None,
+                children: vec![],
+            },
+        }
+    }
+}
+
+impl<T, W, O, N, K> WeightedRadixTrie<T, W, O, N, K>
+where
+    W: Ord + Copy,
+    K: ?Sized + TrieKey,
+    O: KeyOrdering<K::Unit>,
+    N: KeyNormalizer<K>,
+{
+    /// Insert label and associated value with its weight into the trie.
+    /// Values and weights will be override if the label provided is already in the trie
+    pub fn insert(&mut self, label: &K, value: T, weight: W) {
+        let (path, growth) = match self.trie.insertion_path(label) {
+            Some(found) => found,
+            None => return,
+        };
+        self.grow(&path, growth);
+        self.trie.insert(label, (weight, value));
+        Self::refresh(self.trie.root(), &mut self.bounds, &path);
+    }
+
+    /// Returns the borrowed value associated with related label
+    pub fn find(&self, label: &K) -> Option<&T> {
+        self.trie.find(label).map(|(_, value)| value)
+    }
+
+    /// Returns the mutable borrowed value associated with related label
+    pub fn find_mut(&mut self, label: &K) -> Option<&mut T> {
+        self.trie.find_mut(label).map(|(_, value)| value)
+    }
+
+    /// Returns the weight associated with related label
+    pub fn weight(&self, label: &K) -> Option<W> {
+        self.trie.find(label).map(|(weight, _)| *weight)
+    }
+
+    /// Changes the weight associated with related label.
+    /// Returns false if the label does not exist in the trie
+    pub fn set_weight(&mut self, label: &K, weight: W) -> bool {
+        let path = match self.trie.insertion_path(label) {
+            Some((path, Growth::Existing)) => path,
+            _ => return false,
+        };
+        match self.trie.find_mut(label) {
+            Some((old_weight, _)) => *old_weight = weight,
+            None => return false,
+        }
+        Self::refresh(self.trie.root(), &mut self.bounds, &path);
+        true
+    }
+
+    /// Removes the value associated with related label.
+    /// If the provided label does not exist in the trie, return None
+    pub fn remove(&mut self, label: &K) -> Option<T> {
+        let mut path = match self.trie.insertion_path(label) {
+            Some((path, Growth::Existing)) => path,
+            _ => return None,
+        };
+        let index = path.pop()?;
+        let parent = Self::element(self.trie.root(), &path);
+        let parent_is_node = parent.is_node();
+        let (_, value) = self.trie.remove(label)?;
+        // the bounds change the same way as the elements when the value is removed
+        let parent_bound = Self::bound_mut(&mut self.bounds, &path);
+        let mut target = parent_bound.children.remove(index);
+        if target.children.len() > 1 {
+            parent_bound.children.insert(index, target);
+            path.push(index);
+        } else if let Some(child) = target.children.pop() {
+            parent_bound.children.insert(index, child);
+        }
+        if parent_bound.children.len() == 1 && parent_is_node {
+            *parent_bound = parent_bound.children.pop()?;
+        }
+        Self::refresh(self.trie.root(), &mut self.bounds, &path);
+        Some(value)
+    }
+
+    /// Returns the k values with the highest weights, along with their labels and weights,
+    /// where the labels start with the given prefix.
+    /// The values are ordered from the highest weight to the lowest,
+    /// and the values with the same weight are in sorted order of the labels
+    pub fn top_completions(&self, prefix: &K, k: usize) -> Vec<(K::Owned, &T, W)> {
+        let prefix = self.trie.normalize(prefix);
+        let (label, path) = match self.trie.find_prefix_path(&prefix) {
+            Some(found) => found,
+            None => return vec![],
+        };
+        let element = Self::element(self.trie.root(), &path);
+        let bound = path
+            .iter()
+            .fold(&self.bounds, |bound, index| &bound.children[*index]);
+        let score = |_: &K, (weight, _): &(W, T)| *weight;
+        rank::best_first(element, bound, score, label, k)
+            .into_iter()
+            .map(|(label, (_, value), weight)| (label, value, weight))
+            .collect()
+    }
+
+    /// Changes the bounds the same way as the insertion at the path changes the elements
+    fn grow(&mut self, path: &[usize], growth: Growth) {
+        let leaf = || Bound {
+            max: None,
+            children: vec![],
+        };
+        let (index, parent_path) = match path.split_last() {
+            Some(found) => found,
+            None => return,
+        };
+        match growth {
+            Growth::Existing => {}
+            Growth::Leaf => {
+                let parent = Self::bound_mut(&mut self.bounds, parent_path);
+                parent.children.insert(*index, leaf());
+            }
+            Growth::Prefix => {
+                let parent = Self::bound_mut(&mut self.bounds, parent_path);
+                let original = std::mem::replace(&mut parent.children[*index], leaf());
+                parent.children[*index].children.push(original);
+            }
+            Growth::Fork => {
+                let (fork_index, parent_path) = match parent_path.split_last() {
+                    Some(found) => found,
+                    None => return,
+                };
+                let parent = Self::bound_mut(&mut self.bounds, parent_path);
+                let original = std::mem::replace(&mut parent.children[*fork_index], leaf());
+                let mut children = vec![original];
+                children.insert(*index, leaf());
+                parent.children[*fork_index].children = children;
+            }
+        }
+    }
+
+    /// Recomputes the highest weights of the elements along the path, deepest first
+    fn refresh(element: &Element<(W, T), K>, bound: &mut Bound<W>, path: &[usize]) {
+        if let Some((index, rest)) = path.split_first() {
+            Self::refresh(
+                &element.children()[*index],
+                &mut bound.children[*index],
+                rest,
+            );
+        }
+        let mut max = element.value().map(|(weight, _)| *weight);
+        for child in &bound.children {
+            max = max.max(child.max);
+        }
+        bound.max = max;
+    }
+
+    fn element<'a>(root: &'a Element<(W, T), K>, path: &[usize]) -> &'a Element<(W, T), K> {
+        path.iter()
+            .fold(root, |element, index| &element.children()[*index])
+    }
+
+    fn bound_mut<'a>(root: &'a mut Bound<W>, path: &[usize]) -> &'a mut Bound<W> {
+        path.iter()
+            .fold(root, |bound, index| &mut bound.children[*index])
+    }
+}
+
+#[cfg(test)]
+mod weighted_tests {
+    use crate::element::Element;
+    use crate::ordering::CaseInsensitive;
+    use crate::rank::{self, Bound};
+    use crate::weighted::WeightedRadixTrie;
+    use std::cell::Cell;
+
+    /// Finds the top completions by sorting all the labels
+    fn brute_force(words: &[(&str, u64)], prefix: &str, k: usize) -> Vec<(String, u64)> {
+        let mut matched = words
+            .iter()
+            .filter(|(word, _)| word.starts_with(prefix))
+            .map(|(word, weight)| (word.to_string(), *weight))
+            .collect::<Vec<_>>();
+        matched.sort_by(|(word1, weight1), (word2, weight2)| {
+            weight2.cmp(weight1).then(word1.cmp(word2))
+        });
+        matched.truncate(k);
+        matched
+    }
+
+    /// Checks the bounds mirror the elements, with the highest weight under each of them
+    fn assert_bounds(element: &Element<(u64, ())>, bound: &Bound<u64>) {
+        assert_eq!(element.children().len(), bound.children.len());
+        let mut max = element.value().map(|(weight, _)| *weight);
+        for (child, child_bound) in element.children().iter().zip(&bound.children) {
+            assert_bounds(child, child_bound);
+            max = max.max(child_bound.max);
+        }
+        assert_eq!(bound.max, max);
+    }
+
+    fn assert_top(trie: &WeightedRadixTrie<()>, words: &[(&str, u64)]) {
+        assert_bounds(trie.trie.root(), &trie.bounds);
+        for prefix in &["", "W", "Wo", "Won", "Wonder", "Wor", "A", "Axes", "Z"] {
+            for k in 0..6 {
+                let res = trie
+                    .top_completions(prefix, k)
+                    .into_iter()
+                    .map(|(label, _, weight)| (label, weight))
+                    .collect::<Vec<_>>();
+                assert_eq!(res, brute_force(words, prefix, k), "{} {}", prefix, k);
+            }
+        }
+    }
+
+    #[test]
+    fn test_top_completions() {
+        let mut words = vec![
+            ("Won", 5),
+            ("Wonder", 3),
+            ("Wonderful", 8),
+            ("World", 3),
+            ("Worlds", 8),
+            ("Axes", 2),
+            ("Axe", 9),
+        ];
+        let mut trie = WeightedRadixTrie::new();
+        for (word, weight) in &words {
+            trie.insert(word, (), *weight);
+        }
+        assert_top(&trie, &words);
+        assert!(trie.set_weight("Won", 10));
+        assert!(!trie.set_weight("Wo", 10));
+        words[0].1 = 10;
+        assert_top(&trie, &words);
+        assert_eq!(trie.weight("Won"), Some(10));
+    }
+
+    #[test]
+    fn test_cache_follows_removal() {
+        let mut words = vec![("exe", 3), ("exec", 9), ("execute", 7), ("example", 1)];
+        let mut trie = WeightedRadixTrie::new();
+        for (word, weight) in &words {
+            trie.insert(word, (), *weight);
+        }
+        assert_eq!(trie.remove("exec"), Some(()));
+        assert_eq!(trie.remove("exec"), None);
+        assert_eq!(trie.remove("ex"), None);
+        words.remove(1);
+        assert_bounds(trie.trie.root(), &trie.bounds);
+        assert_eq!(
+            trie.top_completions("ex", 1),
+            vec![("execute".into(), &(), 7)]
+        );
+        assert_eq!(trie.remove("exe"), Some(()));
+        assert_eq!(trie.remove("execute"), Some(()));
+        assert_eq!(
+            trie.top_completions("", 5),
+            vec![("example".into(), &(), 1)]
+        );
+        assert_eq!(trie.remove("example"), Some(()));
+        assert!(trie.bounds.children.is_empty());
+        assert_eq!(trie.bounds.max, None);
+    }
+
+    #[test]
+    fn test_case_insensitive() {
+        let mut trie = WeightedRadixTrie::<(), u64, _>::with_ordering(CaseInsensitive);
+        trie.insert("Won", (), 5);
+        trie.insert("WONDER", (), 3);
+        trie.insert("wonderful", (), 8);
+        trie.insert("world", (), 1);
+        let res = trie.top_completions("WoNd", 2);
+        let expected = vec![("wonderful".into(), &(), 8), ("WONDER".into(), &(), 3)];
+        assert_eq!(res, expected);
+        assert!(trie.set_weight("WONDERFUL", 0));
+        assert_eq!(trie.top_completions("won", 1), vec![("Won".into(), &(), 5)]);
+        assert_eq!(trie.remove("won"), Some(()));
+        assert_eq!(
+            trie.top_completions("W", 1),
+            vec![("WONDER".into(), &(), 3)]
+        );
+    }
+
+    #[test]
+    fn test_top_completions_best_first() {
+        // the weights rise in sorted order of the labels, so the best values are found last in order
+        let mut trie = WeightedRadixTrie::new();
+        for weight in 0..1000 {
+            trie.insert(&format!("key{:03}", weight), (), weight);
+        }
+        let visited = Cell::new(0);
+        let score = |_: &str, (weight, _): &(u64, ())| {
+            visited.set(visited.get() + 1);
+            *weight
+        };
+        let res = rank::best_first(trie.trie.root(), &trie.bounds, score, String::new(), 3);
+        let labels = res.iter().map(|(label, _, _)| label).collect::<Vec<_>>();
+        assert_eq!(labels, vec!["key999", "key998", "key997"]);
+        // only the values taken are visited, the other subtrees stay in the frontier
+        assert_eq!(visited.get(), 3);
+    }
+}